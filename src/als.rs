@@ -0,0 +1,740 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use crate::board::*;
+use crate::solvers::*;
+
+use itertools::Itertools;
+
+
+/// ### AlmostLockedSet
+///
+/// A set of unsolved cells within a house that together have exactly one more
+/// probability than the number of cells. A single cell with two probabilities is
+/// an almost locked set too. When one of the digits is removed from the set, the
+/// remaining digits are locked in the cells.
+///
+/// ### Attributes
+///    house (House): The house the cells are in
+///    cells (Vec<u8>): The index of the cells, sorted
+///    digits (HashSet<u8>): The probabilities of all cells combined
+#[derive(Clone, Debug)]
+pub struct AlmostLockedSet {
+    pub house: House,
+    pub cells: Vec<u8>,
+    pub digits: HashSet<u8>,
+}
+
+impl AlmostLockedSet {
+    /// Gets the cells of the set in which a digit is probable
+    ///
+    /// ### Arguments
+    ///    board (&Board): the sudoku
+    ///    digit (u8): the digit
+    ///
+    /// ### Returns
+    ///    Vec<u8>: the index of the cells
+    pub fn cells_with(&self, board: &Board, digit: u8) -> Vec<u8> {
+        return self.cells
+            .iter()
+            .filter(|i| board.cells[**i as usize].contains(&digit))
+            .copied()
+            .collect()
+    }
+
+    /// Checks if the set has cells in common with another set
+    pub fn overlaps(&self, other: &AlmostLockedSet) -> bool {
+        return self.cells.iter().any(|i| other.cells.contains(i))
+    }
+}
+
+
+/// Gets all almost locked sets of the board. Sets that lie in multiple houses
+/// are only returned once
+///
+/// ### Arguments
+///   board (&Board): the sudoku
+///
+/// ### Returns
+///   Vec<AlmostLockedSet>: the almost locked sets
+pub fn almost_locked_sets(board: &Board) -> Vec<AlmostLockedSet> {
+    let mut sets: Vec<AlmostLockedSet> = vec![];
+    let mut found: HashSet<Vec<u8>> = HashSet::new();
+
+    for i in 0..9 {
+        for (house, subset) in [
+            (House::Row(i), board.row(i)),
+            (House::Column(i), board.column(i)),
+            (House::Block(i), board.block(i)),
+        ] {
+            let missing: Vec<Cell> = subset.missing();
+
+            for n in 1..=missing.len() {
+                for cells in missing.iter().combinations(n) {
+                    let digits: HashSet<u8> = cells
+                        .iter()
+                        .flat_map(|c| c.as_set())
+                        .collect();
+
+                    if digits.len() != n + 1 {
+                        continue
+                    }
+
+                    let indices: Vec<u8> = cells.iter().map(|c| c.index).sorted().collect();
+                    if found.insert(indices.clone()) {
+                        sets.push(AlmostLockedSet { house, cells: indices, digits });
+                    }
+                }
+            }
+        }
+    }
+
+    return sets
+}
+
+
+/// Gets the restricted common digits of two sets. A digit is a restricted common
+/// when it is probable in both sets and every cell of the one set with the digit
+/// sees every cell of the other set with the digit. At most one of the sets can
+/// hold the digit then. The sets can't overlap.
+///
+/// ### Arguments
+///   board (&Board): the sudoku
+///   a (&AlmostLockedSet): the first set
+///   b (&AlmostLockedSet): the second set
+///
+/// ### Returns
+///   Vec<u8>: the restricted common digits, sorted
+pub fn restricted_commons(board: &Board, a: &AlmostLockedSet, b: &AlmostLockedSet) -> Vec<u8> {
+    if a.overlaps(b) {
+        return vec![]
+    }
+
+    return a.digits
+        .intersection(&b.digits)
+        .filter(|d| sees_all(board, &a.cells_with(board, **d), &b.cells_with(board, **d)))
+        .copied()
+        .sorted()
+        .collect()
+}
+
+
+/// Checks if every cell of the first list sees every cell of the second list
+fn sees_all(board: &Board, first: &[u8], second: &[u8]) -> bool {
+    return first
+        .iter()
+        .all(|i| second.iter().all(|j| board.cells[*i as usize].sees(&board.cells[*j as usize])))
+}
+
+
+/// Removes a digit from every cell that sees all cells of the sets in which the
+/// digit is probable. The cells of the sets themselves are skipped
+///
+/// ### Arguments
+///   board (&Board): the sudoku
+///   digit (u8): the digit
+///   sets (&[&AlmostLockedSet]): the sets
+///
+/// ### Returns
+///   HashMap<u8, HashSet<u8>>: the probabilities that can be removed
+fn remove_seen(board: &Board, digit: u8, sets: &[&AlmostLockedSet]) -> HashMap<u8, HashSet<u8>> {
+    let cells: Vec<u8> = sets
+        .iter()
+        .flat_map(|s| s.cells_with(board, digit))
+        .collect();
+
+    return board.cells
+        .iter()
+        .filter(|c| !c.solved() && c.contains(&digit))
+        .filter(|c| !sets.iter().any(|s| s.cells.contains(&c.index)))
+        .filter(|c| sees_all(board, &[c.index], &cells))
+        .map(|c| (c.index, HashSet::from([digit])))
+        .collect()
+}
+
+
+/// Adds the probabilities of the second hashmap to the first
+fn merge(hashmap: &mut HashMap<u8, HashSet<u8>>, other: HashMap<u8, HashSet<u8>>) {
+    for (i, p) in other {
+        hashmap.entry(i).or_default().extend(p);
+    }
+}
+
+
+/// Gets the cells of all sets
+fn set_cells(sets: &[&AlmostLockedSet]) -> Vec<u8> {
+    return sets.iter().flat_map(|s| s.cells.iter().copied()).collect()
+}
+
+
+/// Gets the digits of all sets
+fn set_digits(sets: &[&AlmostLockedSet]) -> Vec<u8> {
+    return sets.iter().flat_map(|s| s.digits.iter().copied()).collect()
+}
+
+
+/// Gets the sets that share a restricted common digit with each set
+///
+/// ### Arguments
+///   board (&Board): the sudoku
+///   sets (&[AlmostLockedSet]): the sets
+///
+/// ### Returns
+///   Vec<Vec<(usize, u8)>>: for every set, the index of the linked sets and the digit
+fn restricted_links(board: &Board, sets: &[AlmostLockedSet]) -> Vec<Vec<(usize, u8)>> {
+    let mut links: Vec<Vec<(usize, u8)>> = vec![vec![]; sets.len()];
+
+    for (a, b) in (0..sets.len()).tuple_combinations() {
+        for digit in restricted_commons(board, &sets[a], &sets[b]) {
+            links[a].push((b, digit));
+            links[b].push((a, digit));
+        }
+    }
+
+    return links
+}
+
+
+/// AlsXZ
+///
+/// Two almost locked sets A and B with a restricted common digit X. Only one of the
+/// sets can hold X, so the other set is locked. Every other digit Z of both sets has
+/// to be in A or B, which removes Z from the cells that see all its cells in A and B.
+///
+/// When the sets share two restricted commons, they are doubly linked. Both sets are
+/// locked then, both restricted commons are removed from the cells that see their
+/// cells in A and B, and every other digit is removed from the cells that see all its
+/// cells in the set it belongs to.
+///
+/// Every digit is tried as the restricted common
+pub struct AlsXZ;
+
+impl SolveProbabilities for AlsXZ {
+    fn kind() -> StrategyKind {
+        return StrategyKind::AlsXZ
+    }
+
+    fn get_strategies(board: &Board) -> Vec<Strategy> {
+        let mut strategies: Vec<Strategy> = vec![];
+        let all: Vec<AlmostLockedSet> = almost_locked_sets(board);
+
+        for x in 1..=9 {
+            let sets: Vec<&AlmostLockedSet> = all.iter().filter(|s| s.digits.contains(&x)).collect();
+
+            for (a, b) in sets.iter().copied().tuple_combinations() {
+                let commons: Vec<u8> = restricted_commons(board, a, b);
+
+                // A doubly linked pair is only handled for its lowest restricted common
+                if commons.first() != Some(&x) {
+                    continue
+                }
+
+                let mut hashmap: HashMap<u8, HashSet<u8>> = HashMap::new();
+
+                if commons.len() == 1 {
+                    for z in a.digits.intersection(&b.digits).filter(|d| **d != x) {
+                        merge(&mut hashmap, remove_seen(board, *z, &[a, b]));
+                    }
+                } else {
+                    for digit in &commons {
+                        merge(&mut hashmap, remove_seen(board, *digit, &[a, b]));
+                    }
+                    for set in [a, b] {
+                        for digit in set.digits.iter().filter(|d| !commons.contains(d)) {
+                            merge(&mut hashmap, remove_seen(board, *digit, &[set]));
+                        }
+                    }
+                }
+
+                if hashmap.is_empty() {
+                    continue
+                }
+
+                strategies.push(
+                    Strategy::new(
+                        Self::kind(),
+                        hashmap
+                    ).with_houses(vec![a.house, b.house], vec![])
+                    .with_pattern(set_cells(&[a, b]), set_digits(&[a, b]))
+                );
+            }
+        }
+
+        return strategies
+    }
+}
+
+
+/// AlsXYWing
+///
+/// Three almost locked sets A, B and C. A and C share the restricted common X, B and
+/// C share the restricted common Y. When a digit Z is in neither A nor B, both sets
+/// are locked, so X and Y can't be in C. That leaves too few digits for C, so Z is
+/// in A or B. Z is removed from the cells that see all its cells in A and B. A and B
+/// can't overlap, or a single cell with Z could hold it for both sets.
+///
+/// Every digit is tried as Z
+pub struct AlsXYWing;
+
+impl SolveProbabilities for AlsXYWing {
+    fn kind() -> StrategyKind {
+        return StrategyKind::AlsXYWing
+    }
+
+    fn get_strategies(board: &Board) -> Vec<Strategy> {
+        let mut strategies: Vec<Strategy> = vec![];
+        let sets: Vec<AlmostLockedSet> = almost_locked_sets(board);
+        let links: Vec<Vec<(usize, u8)>> = restricted_links(board, &sets);
+
+        for z in 1..=9 {
+            for (c, wings) in links.iter().enumerate() {
+                for ((a, x), (b, y)) in wings.iter().tuple_combinations() {
+                    if a == b || x == y || *x == z || *y == z {
+                        continue
+                    }
+
+                    let (a, b): (&AlmostLockedSet, &AlmostLockedSet) = (&sets[*a], &sets[*b]);
+                    if a.overlaps(b) || !a.digits.contains(&z) || !b.digits.contains(&z) {
+                        continue
+                    }
+
+                    let hashmap: HashMap<u8, HashSet<u8>> = remove_seen(board, z, &[a, b]);
+
+                    if hashmap.is_empty() {
+                        continue
+                    }
+
+                    strategies.push(
+                        Strategy::new(
+                            Self::kind(),
+                            hashmap
+                        ).with_houses(vec![a.house, b.house], vec![sets[c].house])
+                        .with_pattern(set_cells(&[a, b, &sets[c]]), set_digits(&[a, b, &sets[c]]))
+                    );
+                }
+            }
+        }
+
+        return strategies
+    }
+}
+
+
+/// AlsChain
+///
+/// A chain of at least three almost locked sets, where every set shares a restricted common with
+/// the next set and the restricted commons of consecutive links differ. When a digit
+/// Z is not in the first set, the set is locked, so the first restricted common is in
+/// the first set and not in the second set. The second set is locked then, and so on,
+/// until the last set holds Z. When Z differs from the first and last restricted
+/// common, Z is removed from the cells that see all its cells in the first and last set.
+/// A chain of two sets is an ALS-XZ, so it is left to AlsXZ.
+///
+/// Every digit is tried as Z
+pub struct AlsChain;
+
+impl SolveProbabilities for AlsChain {
+    fn kind() -> StrategyKind {
+        return StrategyKind::AlsChain
+    }
+
+    fn get_strategies(board: &Board) -> Vec<Strategy> {
+        let mut strategies: Vec<Strategy> = vec![];
+        let sets: Vec<AlmostLockedSet> = almost_locked_sets(board);
+        let links: Vec<Vec<(usize, u8)>> = restricted_links(board, &sets);
+
+        for z in 1..=9 {
+            let mut found: HashSet<Vec<(u8, u8)>> = HashSet::new();
+
+            for start in (0..sets.len()).filter(|s| sets[*s].digits.contains(&z)) {
+                // A state is a set and the restricted common it was reached with
+                let mut parent: HashMap<(usize, u8), (usize, u8)> = HashMap::new();
+                let mut queue: VecDeque<(usize, u8)> = VecDeque::from([(start, z)]);
+
+                while let Some((set, previous)) = queue.pop_front() {
+                    for (next, digit) in &links[set] {
+                        if *digit == previous || *next == start || parent.contains_key(&(*next, *digit)) {
+                            continue
+                        }
+
+                        parent.insert((*next, *digit), (set, previous));
+                        queue.push_back((*next, *digit));
+
+                        // The chain needs a set between the first and the last set
+                        if *digit == z || set == start || !sets[*next].digits.contains(&z) {
+                            continue
+                        }
+
+                        let hashmap: HashMap<u8, HashSet<u8>> = remove_seen(board, z, &[&sets[start], &sets[*next]]);
+
+                        let key: Vec<(u8, u8)> = hashmap
+                            .iter()
+                            .flat_map(|(i, p)| p.iter().map(|d| (*i, *d)))
+                            .sorted()
+                            .collect();
+
+                        if hashmap.is_empty() || !found.insert(key) {
+                            continue
+                        }
+
+                        let mut chain: Vec<&AlmostLockedSet> = vec![&sets[*next]];
+                        let mut state: (usize, u8) = (*next, *digit);
+                        while state.0 != start {
+                            state = parent[&state];
+                            chain.push(&sets[state.0]);
+                        }
+                        chain.reverse();
+
+                        strategies.push(
+                            Strategy::new(
+                                Self::kind(),
+                                hashmap
+                            ).with_houses(chain.iter().map(|s| s.house).collect(), vec![])
+                            .with_pattern(set_cells(&chain), set_digits(&chain))
+                        );
+                    }
+                }
+            }
+        }
+
+        return strategies
+    }
+}
+
+
+/// DeathBlossom
+///
+/// A stem cell with a petal for each of its probabilities. A petal is an almost
+/// locked set in which every cell with the digit of the petal sees the stem. The stem
+/// holds one of its digits, which removes that digit from its petal and locks the
+/// petal. When a digit Z is in every petal but not in the stem, Z is in one of the
+/// petals, so Z is removed from the cells that see all its cells in the petals.
+///
+/// Every digit is tried as Z
+pub struct DeathBlossom;
+
+impl SolveProbabilities for DeathBlossom {
+    fn kind() -> StrategyKind {
+        return StrategyKind::DeathBlossom
+    }
+
+    fn get_strategies(board: &Board) -> Vec<Strategy> {
+        let mut strategies: Vec<Strategy> = vec![];
+        let all: Vec<AlmostLockedSet> = almost_locked_sets(board);
+
+        for z in 1..=9 {
+            let sets: Vec<&AlmostLockedSet> = all.iter().filter(|s| s.digits.contains(&z)).collect();
+            let mut found: HashSet<Vec<u8>> = HashSet::new();
+
+            for stem in board.cells.iter().filter(|c| !c.solved() && !c.contains(&z)) {
+                // The sets that can be the petal of every digit of the stem
+                let petals: Vec<Vec<&AlmostLockedSet>> = stem.probabilities
+                    .iter()
+                    .map(|d| sets
+                        .iter()
+                        .copied()
+                        .filter(|s| s.digits.contains(d) && !s.cells.contains(&stem.index))
+                        .filter(|s| sees_all(board, &[stem.index], &s.cells_with(board, *d)))
+                        .collect())
+                    .collect();
+
+                if petals.iter().any(|p| p.is_empty()) {
+                    continue
+                }
+
+                let targets: Vec<u8> = board.cells
+                    .iter()
+                    .filter(|c| c.contains(&z) && !c.solved())
+                    .map(|c| c.index)
+                    .collect();
+
+                for (blossom, targets) in blossoms(board, z, &petals, vec![], targets) {
+                    if !found.insert(targets.clone()) {
+                        continue
+                    }
+
+                    let chain: Vec<Link> = stem.probabilities
+                        .iter()
+                        .zip(blossom.iter())
+                        .map(|(d, petal)| Link {
+                            from: Node { cells: vec![stem.index], digit: *d },
+                            to: Node { cells: petal.cells_with(board, *d), digit: *d },
+                            strong: false,
+                        })
+                        .collect();
+
+                    strategies.push(
+                        Strategy::new(
+                            Self::kind(),
+                            targets.iter().map(|i| (*i, HashSet::from([z]))).collect()
+                        ).with_houses(blossom.iter().map(|p| p.house).collect(), vec![])
+                        .with_chain(chain)
+                        .with_pattern(
+                            set_cells(&blossom).into_iter().chain([stem.index]).collect(),
+                            set_digits(&blossom)
+                        )
+                    );
+                }
+            }
+        }
+
+        return strategies
+    }
+}
+
+
+/// Picks a petal for every digit of the stem, where the petals can't overlap. The
+/// cells that see all cells of the picked petals with the digit Z are kept as targets,
+/// a blossom without targets is dropped
+///
+/// ### Arguments
+///   board (&Board): the sudoku
+///   z (u8): the digit that is removed
+///   petals (&[Vec<&AlmostLockedSet>]): the sets that can be the petal of each digit
+///   blossom (Vec<&AlmostLockedSet>): the petals that are picked so far
+///   targets (Vec<u8>): the cells that see all picked cells with Z so far
+///
+/// ### Returns
+///   Vec<(Vec<&AlmostLockedSet>, Vec<u8>)>: the petals of every blossom with its targets
+fn blossoms<'a>(
+    board: &Board,
+    z: u8,
+    petals: &[Vec<&'a AlmostLockedSet>],
+    blossom: Vec<&'a AlmostLockedSet>,
+    targets: Vec<u8>,
+) -> Vec<(Vec<&'a AlmostLockedSet>, Vec<u8>)> {
+    if blossom.len() == petals.len() {
+        return vec![(blossom, targets)]
+    }
+
+    let mut found: Vec<(Vec<&AlmostLockedSet>, Vec<u8>)> = vec![];
+
+    for petal in &petals[blossom.len()] {
+        if blossom.iter().any(|p| p.overlaps(petal)) {
+            continue
+        }
+
+        let cells: Vec<u8> = petal.cells_with(board, z);
+        let remaining: Vec<u8> = targets
+            .iter()
+            .filter(|i| !petal.cells.contains(i) && sees_all(board, &[**i], &cells))
+            .copied()
+            .collect();
+
+        if remaining.is_empty() {
+            continue
+        }
+
+        let mut next: Vec<&AlmostLockedSet> = blossom.clone();
+        next.push(petal);
+        found.extend(blossoms(board, z, petals, next, remaining));
+    }
+
+    return found
+}
+
+
+/// Gets for every unsolved cell the almost locked sets it can take a digit from. A
+/// digit is taken from a set when the cell isn't part of the set, and every cell of
+/// the set with the digit sees the cell
+///
+/// ### Arguments
+///   board (&Board): the sudoku
+///   sets (&[AlmostLockedSet]): the almost locked sets of the board
+///
+/// ### Returns
+///   HashMap<u8, Vec<(usize, u8)>>: for every unsolved cell, the index of the sets and the digit
+fn excluded_sets(board: &Board, sets: &[AlmostLockedSet]) -> HashMap<u8, Vec<(usize, u8)>> {
+    return board.cells
+        .iter()
+        .filter(|c| !c.solved())
+        .map(|c| (
+            c.index,
+            sets.iter()
+                .enumerate()
+                .filter(|(_, s)| !s.cells.contains(&c.index))
+                .flat_map(|(i, s)| c.probabilities
+                    .iter()
+                    .filter(|d| s.digits.contains(d) && sees_all(board, &[c.index], &s.cells_with(board, **d)))
+                    .map(move |d| (i, *d)))
+                .collect()
+        ))
+        .collect()
+}
+
+
+/// Removes the probabilities of aligned cells that are in no valid combination. A
+/// combination gives every cell one of its probabilities. It is invalid when two of
+/// the cells that see each other get the same digit, or when it takes two digits
+/// from an almost locked set, which would leave the set with too few digits
+///
+/// ### Arguments
+///   sets (&[AlmostLockedSet]): the almost locked sets of the board
+///   excluded (&HashMap<u8, Vec<(usize, u8)>>): the sets every unsolved cell can take a digit from
+///   cells (&[&Cell]): the aligned cells
+///
+/// ### Returns
+///   HashMap<u8, HashSet<u8>>: the probabilities that can be removed
+fn aligned_exclusion(
+    sets: &[AlmostLockedSet],
+    excluded: &HashMap<u8, Vec<(usize, u8)>>,
+    cells: &[&Cell],
+) -> HashMap<u8, HashSet<u8>> {
+    // For every set outside the aligned cells, the aligned cells and digits that take a digit from it
+    let mut taken: HashMap<usize, Vec<(usize, u8)>> = HashMap::new();
+    for (i, c) in cells.iter().enumerate() {
+        for (set, digit) in &excluded[&c.index] {
+            taken.entry(*set).or_default().push((i, *digit));
+        }
+    }
+
+    // Only the sets that can lose two digits exclude a combination
+    let excluding: Vec<Vec<(usize, u8)>> = taken
+        .into_iter()
+        .filter(|(set, _)| !cells.iter().any(|c| sets[*set].cells.contains(&c.index)))
+        .map(|(_, e)| e)
+        .filter(|e| e.iter().map(|(_, d)| d).unique().count() >= 2)
+        .collect();
+
+    let mut valid: Vec<HashSet<u8>> = vec![HashSet::new(); cells.len()];
+
+    for combination in cells.iter().map(|c| c.probabilities.iter().copied()).multi_cartesian_product() {
+        let duplicate: bool = (0..cells.len())
+            .tuple_combinations()
+            .any(|(i, j)| combination[i] == combination[j] && cells[i].sees(cells[j]));
+
+        if duplicate {
+            continue
+        }
+
+        if excluding.iter().any(|e| e.iter().filter(|(i, d)| combination[*i] == *d).map(|(_, d)| d).unique().count() >= 2) {
+            continue
+        }
+
+        for (i, d) in combination.iter().enumerate() {
+            valid[i].insert(*d);
+        }
+    }
+
+    return cells
+        .iter()
+        .zip(valid.iter())
+        .map(|(c, v)| (c.index, c.as_set().difference(v).copied().collect::<HashSet<u8>>()))
+        .filter(|(_, p)| !p.is_empty())
+        .collect()
+}
+
+
+/// Gets every group of unsolved cells that lie in one house, or that all see the same
+/// unsolved cell. Only these groups are built, instead of every combination of cells
+///
+/// ### Arguments
+///   board (&Board): the sudoku
+///   size (usize): the number of cells in a group
+///
+/// ### Returns
+///   Vec<Vec<&Cell>>: the groups, with the cells sorted by index
+fn aligned_groups(board: &Board, size: usize) -> Vec<Vec<&Cell>> {
+    let unsolved: Vec<&Cell> = board.cells.iter().filter(|c| !c.solved()).collect();
+
+    // The cells of every house, and the peers of every unsolved cell
+    let neighbourhoods: Vec<Vec<&Cell>> = (0..9)
+        .flat_map(|i| [House::Row(i), House::Column(i), House::Block(i)])
+        .map(|h| unsolved.iter().copied().filter(|c| cell_houses(c).contains(&h)).collect())
+        .chain(unsolved.iter().map(|peer| unsolved.iter().copied().filter(|c| c.sees(peer)).collect()))
+        .collect();
+
+    let mut groups: Vec<Vec<&Cell>> = vec![];
+    let mut found: HashSet<Vec<u8>> = HashSet::new();
+
+    for cells in neighbourhoods {
+        for group in cells.into_iter().combinations(size) {
+            if found.insert(group.iter().map(|c| c.index).collect()) {
+                groups.push(group);
+            }
+        }
+    }
+
+    return groups
+}
+
+
+/// Gets the strategies of aligned exclusion for every group of unsolved cells that
+/// lie in one house or have an unsolved cell they all see. Which almost locked sets
+/// exclude a combination doesn't depend on the group, so it is looked up once for
+/// every cell
+///
+/// ### Arguments
+///   board (&Board): the sudoku
+///   size (usize): the number of aligned cells
+///   kind (StrategyKind): the kind of the strategy
+///
+/// ### Returns
+///   Vec<Strategy>: the strategies
+fn aligned_logic(board: &Board, size: usize, kind: StrategyKind) -> Vec<Strategy> {
+    let mut strategies: Vec<Strategy> = vec![];
+    let sets: Vec<AlmostLockedSet> = almost_locked_sets(board);
+    let excluded: HashMap<u8, Vec<(usize, u8)>> = excluded_sets(board, &sets);
+
+    for cells in aligned_groups(board, size) {
+        let hashmap: HashMap<u8, HashSet<u8>> = aligned_exclusion(&sets, &excluded, &cells);
+
+        if hashmap.is_empty() {
+            continue
+        }
+
+        // The houses that hold all aligned cells, if any
+        let houses: Vec<House> = cell_houses(cells[0])
+            .into_iter()
+            .filter(|h| cells.iter().all(|c| cell_houses(c).contains(h)))
+            .collect();
+
+        strategies.push(
+            Strategy::new(
+                kind,
+                hashmap
+            ).with_houses(houses, vec![])
+            .with_pattern(
+                cells.iter().map(|c| c.index).collect(),
+                cells.iter().flat_map(|c| c.probabilities.iter().copied()).collect()
+            )
+        );
+    }
+
+    return strategies
+}
+
+
+/// AlignedPairExclusion
+///
+/// Two unsolved cells that see each other can't hold the same digit. Every combination
+/// of their probabilities is tested, and a combination that would remove two digits
+/// from an almost locked set, like both digits of a bivalue cell that sees both
+/// cells, is excluded. Probabilities that are in no valid combination are removed.
+/// The cells don't have to share a house, as long as they have a common peer.
+pub struct AlignedPairExclusion;
+
+impl SolveProbabilities for AlignedPairExclusion {
+    fn kind() -> StrategyKind {
+        return StrategyKind::AlignedPairExclusion
+    }
+
+    fn get_strategies(board: &Board) -> Vec<Strategy> {
+        return aligned_logic(board, 2, Self::kind())
+    }
+}
+
+
+/// AlignedTripleExclusion
+///
+/// The same as the aligned pair exclusion, but for three unsolved cells with a common
+/// peer.
+pub struct AlignedTripleExclusion;
+
+impl SolveProbabilities for AlignedTripleExclusion {
+    fn kind() -> StrategyKind {
+        return StrategyKind::AlignedTripleExclusion
+    }
+
+    fn get_strategies(board: &Board) -> Vec<Strategy> {
+        return aligned_logic(board, 3, Self::kind())
+    }
+}
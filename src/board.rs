@@ -0,0 +1,1329 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+
+/// Contains the formulas to get the index of the row, column and block
+///
+/// The input is the index of a certain cell
+///
+enum SubsetIndexToSubset {}
+
+impl SubsetIndexToSubset {
+    fn row(i: u8, iter: u8) -> u8 { i * 9 + iter }
+    fn column(i: u8, iter: u8) -> u8 {
+        iter * 9 + i
+    }
+    fn block(i: u8, iter: u8) -> u8 { 27 * (i / 3) + 3 * (i % 3) + (iter / 3) * 9 + (iter % 3) }
+}
+
+
+enum BoardIndexFormulas{}
+
+impl BoardIndexFormulas {
+    fn row(i: u8, iter: u8) -> u8 {
+        (i / 9) * 9 + iter
+    }
+    fn column(i: u8, iter: u8) -> u8 {
+        i % 9 + 9 * iter
+    }
+    fn block(i: u8, iter: u8) -> u8 { SubsetIndexToSubset::block(i / 3 - i / 9 * 3 + i / 27 * 3, iter) }
+}
+
+pub struct Subset {
+    pub indices: [u8; 9],
+    pub cells: [Cell; 9]
+}
+
+
+/// Initializes a subset of Cells from a board instance based on the requested
+/// index and passed function. Eligible functions can be found in SubsetIndexToSubset
+/// and BoardIndexFormulas
+///
+/// Args:
+///     board (&Board): the board
+///     i (u8): index, can be the index of the requested subset, or the
+///             index of the cell on the board. This changeable through the
+///             passed function
+///     func (&dyn Fn(u8, u8) -> u8): formula that determines the cells
+///
+/// Returns:
+///     Subset
+impl Subset {
+    fn from_board(board: &Board, i: u8, func: &dyn Fn(u8, u8) -> u8) -> Subset {
+        return Subset {
+            indices: (0..9)
+                .map(|x| func(i, x))
+                .collect::<Vec<u8>>()
+                .try_into()
+                .expect(""),
+            cells: (0..9)
+                .map(|x| board.cells[usize::from(func(i, x))].clone())
+                .collect::<Vec<Cell>>()
+                .try_into()
+                .expect("")
+        }
+    }
+
+    pub fn is_solved(&self) -> bool {
+        return self.cells
+            .iter()
+            .all(|c| c.solved())
+    }
+
+    /// Wrapper for Vector.contains. Looks into the values of the
+    /// subset if it contains the passed value
+    ///
+    /// ### Args:
+    ///   value (u8): The value that should be checked
+    ///
+    /// ### Return:
+    ///   True if the value is in self.values
+    ///
+    pub fn contains(&self, value: &u8) -> bool {
+        return self.cells
+            .iter()
+            .filter(|c| c.solved())
+            .any(|c| c.value() == *value)
+    }
+
+    pub fn missing(&self) -> Vec<Cell> {
+        return self.cells
+            .clone()
+            .into_iter()
+            .filter(|c| !c.solved())
+            .collect::<Vec<Cell>>();
+    }
+
+    pub fn indices_missing(&self) -> Vec<u8> {
+        return self.cells
+            .iter()
+            .filter(|c| !c.solved())
+            .map(|c| c.index)
+            .collect();
+    }
+
+    /// Get the values that are solved in Self
+    ///
+    /// ### Returns:
+    ///   The solved values (Vec<u8>)
+    pub fn values_solved(&self) -> HashSet<u8> {
+        return self.cells
+            .iter()
+            .filter(|c| c.solved())
+            .map(|c| c.value())
+            .collect()
+    }
+
+    /// Returns all unique solved values that are in self or other
+    ///
+    /// # Args:
+    ///   other (Subset): The other subset
+    ///
+    /// # Returns:
+    /// A vector containing the solved values
+    pub fn union(&self, other: &Self) -> Vec<u8> {
+        let mut union: Vec<u8> = self.cells
+            .iter()
+            .filter(|c| c.solved())
+            .map(|c| c.value())
+            .collect::<Vec<u8>>();
+        for v in other.cells
+            .iter()
+            .filter(|c| c.solved())
+            .map(|c| c.value())
+            .collect::<Vec<u8>>() {
+            if v != 0 || union.contains(&v) {
+                continue
+            }
+            union.push(v)
+        }
+
+        return union;
+    }
+}
+
+
+pub struct Board {
+    pub cells: [Cell; 81],
+}
+
+impl Board {
+
+    /// Initialize board from a string
+    pub fn from_string(string: &str) -> Board {
+        let current_state: [Cell; 81] = string
+            .chars()
+            .enumerate()
+            .map(|(i, char)| Cell::from_number(i, char.to_digit(10).unwrap() as u8))
+            .collect::<Vec<Cell>>()
+            .try_into()
+            .expect("The string must be 81 characters");
+
+        return Board {
+            cells: current_state,
+        };
+    }
+
+    /// ### Prints the board
+    ///
+    /// Prints the board in a human readable format, creates nice blocks.
+    /// Also prints the percentage of the board that is solved, and the number of probabilities
+    /// left in the board. This indicates the progression of a certain strategy.
+    pub fn print_board(&self) {
+        let string: String = self.to_string();
+        let percentage_completed: f32 = self.cells
+            .iter()
+            .filter(|c| c.solved())
+            .count() as f32 / 81f32 * 100f32;
+
+        println!();
+        println!("{:?} - {:?}%", self.uncertainty(), percentage_completed);
+        for (i, s) in string.chars().enumerate() {
+            if i != 0 && i % 9 == 0 {
+                // After each row, print a new line
+                println!();
+            }
+
+            if i != 0 && i % 27 == 0 {
+                // After three rows, print a row separation
+                println!(" —  —  —   —  —  —   —  —  — ");
+            } else if i != 0 && i % 3 == 0 && i % 9 != 0 {
+                // Print column separators
+                print!("|")
+            }
+
+            // Print the solution
+            print!(" {} ", s);
+        }
+        println!()
+    }
+
+    /// Returns the sum of the number of probabilities
+    /// Acts as measure towards solving a puzzle
+    ///
+    /// ### Returns:
+    ///   Number of probabilities (usize)
+    fn uncertainty(&self) -> usize {
+        return self.cells
+            .iter()
+            .map(|c| c.probabilities.len())
+            .sum::<usize>()
+    }
+
+    /// ### Get a vector with the index of the blank cells
+    ///
+    /// ### Returns:
+    ///    Vector with the index of the blank cells (Vec<u8>)
+    pub fn blanks(&self) -> Vec<u8> {
+        return self.cells
+            .iter()
+            .filter(|c| !c.solved())
+            .map(|c| c.index)
+            .collect();
+    }
+
+    /// Get the value of a cell
+    ///
+    /// ### Args:
+    ///   index (u8): the index of the cell
+    ///
+    /// ### Returns:
+    ///   The value of the cell (u8)
+    pub fn get(&self, index: u8) -> u8 {
+        if index > 80 {
+            panic!("You've tried getting a number with a to high index. Not allowed")
+        }
+        return self.cells[index as usize].value()
+    }
+
+    fn set(&mut self, index: u8, solution: u8) {
+        // Sets a solution into a cell. Changes the numbers and add the change to the history
+        //
+        // Args:
+        //     index (u8): the index on the board in which you want the solution to be placed
+        //     solution (u8): The solution 1-9
+        //
+        self.cells[index as usize].set(&solution);
+    }
+
+    /// Try to set a solution into a cell
+    ///
+    /// Args:
+    ///     index (u8): the index on the board in which you want the solution to be placed
+    ///     solution (u8): The solution 1-9
+    ///
+    /// Returns:
+    ///     true if the set is valid, false if not (bool)
+    pub fn try_set(&mut self, index: u8, solution: u8) -> bool {
+        if index > 80 {
+            panic!("You've tried setting a number with a to high index. Not allowed")
+        }
+
+        if !self.validate(index, solution) {
+            return false;
+        }
+
+        // Set the solution
+        self.set(index, solution);
+        return true
+    }
+
+    /// Removes multiple probabilities from multiple cells
+    ///
+    /// ### Arguments
+    ///   indices (Vec<u8>)
+    ///   probabilities (Vec<u8>)
+    pub fn remove_probabilities_from_cells(&mut self, indices: Vec<u8>, probabilities: Vec<u8>) {
+        for i in indices {
+            self.cells[i as usize].probabilities.retain(|p| !probabilities.contains(p))
+        }
+    }
+
+    pub fn row(&self, i: u8) -> Subset {
+        return Subset::from_board(self, i, &SubsetIndexToSubset::row)
+    }
+
+    pub fn column(&self, i: u8) -> Subset {
+        return Subset::from_board(self, i, &SubsetIndexToSubset::column)
+    }
+
+    pub fn block(&self, i: u8) -> Subset {
+        return Subset::from_board(self, i, &SubsetIndexToSubset::block)
+    }
+
+    pub fn row_from_index(&self, i: u8) -> Subset {
+        return Subset::from_board(self, i, &BoardIndexFormulas::row)
+    }
+
+    pub fn column_from_index(&self, i: u8) -> Subset {
+        return Subset::from_board(self, i, &BoardIndexFormulas::column)
+    }
+
+    pub fn block_from_index(&self, i: u8) -> Subset {
+        return Subset::from_board(self, i, &BoardIndexFormulas::block)
+    }
+
+    fn validate(&self, index: u8, solution: u8) -> bool {
+        return !(
+            self.block_from_index(index).contains(&solution)
+            || self.row_from_index(index).contains(&solution)
+            || self.column_from_index(index).contains(&solution)
+        )
+    }
+
+    pub fn apply_strategy(&mut self, strategy: Strategy) {
+        for (i, probabilities) in strategy.remove {
+            self.cells[usize::from(i)].probabilities.retain(|p| !probabilities.contains(p))
+        }
+
+        // A value that isn't probable anymore leaves the cell empty, which is a contradiction
+        for (i, value) in strategy.place {
+            self.cells[usize::from(i)].probabilities.retain(|p| *p == value)
+        }
+    }
+
+    /// Checks if the board is solved
+    ///
+    /// ### Returns:
+    ///   true is the puzzle is solved, false if not
+    ///
+    pub fn solved(&self) -> bool {
+        return self.cells
+            .iter()
+            .all(|c| c.solved())
+    }
+
+    /// Checks if the board is consistent. A board is inconsistent when a cell has no
+    /// probabilities left, when a value is solved twice in a house or when a digit
+    /// has no cell left in a house
+    ///
+    /// ### Returns:
+    ///   Result<(), BoardError>: the first inconsistency that is found
+    pub fn check(&self) -> Result<(), BoardError> {
+        if let Some(c) = self.cells.iter().find(|c| c.probabilities.is_empty()) {
+            return Err(BoardError::EmptyCell(c.index))
+        }
+
+        for i in 0..9 {
+            for (house, subset) in [
+                (House::Row(i), self.row(i)),
+                (House::Column(i), self.column(i)),
+                (House::Block(i), self.block(i)),
+            ] {
+                let mut solved: HashSet<u8> = HashSet::new();
+
+                for c in subset.cells.iter().filter(|c| c.solved()) {
+                    if !solved.insert(c.value()) {
+                        return Err(BoardError::DuplicateValue(house, c.value()))
+                    }
+                }
+
+                if let Some(digit) = (1..=9).find(|d| !subset.cells.iter().any(|c| c.contains(d))) {
+                    return Err(BoardError::MissingDigit(house, digit))
+                }
+            }
+        }
+
+        return Ok(())
+    }
+
+}
+
+/// Equality implementation for Board
+///
+/// ### Arguments
+///    other (&Self): other board to compare
+///
+/// ### Returns:
+///    bool: if the board is the same
+impl PartialEq for Board {
+    fn eq(&self, other: &Self) -> bool {
+        return self.cells == other.cells
+    }
+}
+
+/// Clones the board by initializing new board with cloned values
+///
+/// ### Returns
+///   Cloned board (Board)
+impl Clone for Board {
+    fn clone(&self) -> Board {
+        return Board {
+            cells: self.cells.clone(),
+        }
+    }
+}
+
+/// Writes the board as a string of 81 digits, an unsolved cell is written as 0
+impl fmt::Display for Board {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for c in &self.cells {
+            write!(f, "{}", c.value())?;
+        }
+        return Ok(())
+    }
+}
+
+
+/// BoardError
+///
+/// The reason a board is inconsistent and can't be solved anymore
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BoardError {
+    /// The cell with the index has no probabilities left
+    EmptyCell(u8),
+    /// The value is solved in more than one cell of the house
+    DuplicateValue(House, u8),
+    /// The digit can't be placed in any cell of the house
+    MissingDigit(House, u8),
+}
+
+impl BoardError {
+    /// Describes the error in the row/column notation, for example
+    /// "r1c9 has no probabilities left"
+    ///
+    /// ### Returns
+    ///   String: the description
+    pub fn description(&self) -> String {
+        return match self {
+            BoardError::EmptyCell(i) => format!("{} has no probabilities left", cell_name(*i)),
+            BoardError::DuplicateValue(house, d) => format!("{} is solved twice in {}", d, house.name()),
+            BoardError::MissingDigit(house, d) => format!("{} has no place left in {}", d, house.name()),
+        }
+    }
+}
+
+
+/// Cell
+///
+/// Contains the index of the cell and the probabilities. The probabilities are
+/// a vector of u8. If the cell is solved, the vector will contain one value.
+///
+/// The cell also contains methods to get the row, column and block of the cell.
+///
+#[derive(Clone)]
+#[derive(Debug)]
+pub struct Cell {
+    pub index: u8,
+    pub probabilities: Vec<u8>
+}
+
+impl Cell {
+
+    /// Creates a new cell
+    ///
+    /// The cell will have the index and the number as a probability. If the number is 0,
+    /// this means that the cell is empty and the probabilities will be 1-9.
+    ///
+    /// ### Arguments:
+    ///    i (usize): index of the cell
+    ///    number (u8): number in the cell
+    ///
+    /// ### Returns
+    ///   Cell
+    fn from_number(i: usize, number: u8) -> Cell {
+        return Cell {
+            index: i as u8,
+            probabilities: if number == 0 {vec![1,2,3,4,5,6,7,8,9]} else {vec![number]}
+        }
+    }
+
+    /// Get the probabilities as a set
+    ///
+    /// This makers it easier to combine and compare sets
+    ///
+    /// ### Returns
+    ///   HashSet<u8>: set of probabilities
+    pub fn as_set(&self) -> HashSet<u8> {
+        return HashSet::from_iter(self.probabilities.clone())
+    }
+
+    /// Get the index of the row
+    ///
+    /// For example, cell index 10 corresponds to row index 1, 18 to 2
+    ///
+    /// ### Returns
+    ///    u8: index of the row
+    pub fn row(&self) -> u8 {
+        return self.index / 9
+    }
+
+    /// Get the index of the column
+    ///
+    /// For example, cell index 10 corresponds to column index 1
+    ///
+    /// ### Returns
+    ///   u8: index of the column
+    pub fn column(&self) -> u8 {
+        return self.index % 9
+    }
+
+    /// Get the index of the block
+    ///
+    /// For example, cell index 8 corresponds to block index 2, but 10 to 1
+    ///
+    /// ### Returns
+    ///   u8: index of the block
+    pub fn block(&self) -> u8 {
+        return self.index / 3 - self.index / 9 * 3 + self.index / 27 * 3
+    }
+
+    /// Get the name of the cell, with the row and column counted from one
+    ///
+    /// For example, cell index 10 corresponds to r2c2
+    ///
+    /// ### Returns
+    ///   String: the name of the cell
+    pub fn name(&self) -> String {
+        return cell_name(self.index)
+    }
+
+    /// Check if the cell probabilities contain a value
+    ///
+    /// ### Arguments
+    ///  value (&u8): the value that should be checked
+    ///
+    /// ### Returns
+    ///   bool: if the value is in the probabilities
+    pub fn contains(&self, value: &u8) -> bool {
+        return self.probabilities.contains(value)
+    }
+
+    /// Check if the cell sees another cell
+    ///
+    /// Two different cells see each other when they share a row, column or block
+    ///
+    /// ### Arguments
+    ///  other (&Cell): the other cell
+    ///
+    /// ### Returns
+    ///   bool: if the cells are peers
+    pub fn sees(&self, other: &Cell) -> bool {
+        return self.index != other.index && (
+            self.row() == other.row()
+            || self.column() == other.column()
+            || self.block() == other.block()
+        )
+    }
+
+    /// Force set a solution (probabilities will be a vector of one).
+    /// No checks will be executed
+    ///
+    /// ### Arguments
+    ///   value (&u8): the value that should be set
+    pub fn set(&mut self, value: &u8) {
+        self.probabilities = vec![*value]
+    }
+
+    /// Removes a probability from the probabilities
+    ///
+    /// ### Arguments
+    ///   value (u8): the value that should be removed
+    pub fn remove(&mut self, value: u8) {
+        if !self.probabilities.contains(&value) {
+            return;
+        }
+
+        self.probabilities.retain(|p| p != &value)
+    }
+
+    /// Get the value of the cell
+    ///
+    /// If the cell is solved, the value will be the solution, otherwise 0
+    ///
+    /// ### Returns
+    ///   u8: the value of the cell
+    pub fn value(&self) -> u8 {
+        return if self.solved() {self.probabilities[0]} else {0}
+    }
+
+    /// Check if the cell is solved
+    ///
+    /// This is the case if the length of the probabilities is 1
+    ///
+    /// ### Returns
+    ///   bool: if the cell is solved
+    pub fn solved(&self) -> bool {
+        return self.probabilities.len() == 1
+    }
+}
+
+impl PartialEq for Cell {
+    /// Equality implementation for Cell
+    ///
+    /// ### Arguments
+    ///   other (&Self): other cell to compare
+    ///
+    /// ### Returns:
+    ///   bool: if the cell is the same
+    fn eq(&self, other: &Self) -> bool {
+        return self.index == other.index && self.probabilities == other.probabilities
+    }
+}
+
+/// House
+///
+/// A row, column or block of the board, with the index of the house
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum House {
+    Row(u8),
+    Column(u8),
+    Block(u8),
+}
+
+impl House {
+    /// Get the name of the house, counted from one
+    ///
+    /// For example, House::Block(0) corresponds to block 1
+    ///
+    /// ### Returns
+    ///   String: the name of the house
+    pub fn name(&self) -> String {
+        return match self {
+            House::Row(i) => format!("row {}", i + 1),
+            House::Column(i) => format!("column {}", i + 1),
+            House::Block(i) => format!("block {}", i + 1),
+        }
+    }
+}
+
+/// Node
+///
+/// A digit in one or more cells, used in chains. A node is true when the digit
+/// is placed in one of its cells. A node with multiple cells is a grouped node,
+/// its cells share a block and a row or column
+///
+/// ### Attributes
+///    cells (Vec<u8>): The index of the cells, sorted
+///    digit (u8): The digit
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Node {
+    pub cells: Vec<u8>,
+    pub digit: u8,
+}
+
+impl Node {
+    /// Get the name of the node, the digit followed by the cells
+    ///
+    /// For example, digit 3 in cells 10 and 11 corresponds to (3)r2c2,r2c3
+    ///
+    /// ### Returns
+    ///   String: the name of the node
+    pub fn name(&self) -> String {
+        return format!("({}){}", self.digit, self.cells.iter().map(|i| cell_name(*i)).collect::<Vec<String>>().join(","))
+    }
+}
+
+/// Link
+///
+/// A link between two nodes of a chain. When the link is strong, at least one of
+/// the nodes is true. Otherwise the link is weak, and at most one of the nodes is true
+///
+/// ### Attributes
+///    from (Node): The node the link starts from
+///    to (Node): The node the link goes to
+///    strong (bool): If the link is strong
+#[derive(Clone, Debug, PartialEq)]
+pub struct Link {
+    pub from: Node,
+    pub to: Node,
+    pub strong: bool,
+}
+
+/// Inference
+///
+/// A strategy that was applied while following an assumption
+///
+/// ### Attributes
+///    kind (StrategyKind): The technique of the strategy
+///    remove (HashMap<u8, HashSet<u8>>): The index of the cell and the probabilities that were removed
+#[derive(Clone, Debug)]
+pub struct Inference {
+    pub kind: StrategyKind,
+    pub remove: HashMap<u8, HashSet<u8>>,
+}
+
+/// Branch
+///
+/// An assumption and the inferences that follow from it, in the order they were made.
+/// Together the branches of a strategy form the implication tree behind it
+///
+/// ### Attributes
+///    assumption (Node): The digit that is assumed to be placed in the cell
+///    inferences (Vec<Inference>): The strategies that were applied after the assumption
+///    contradiction (bool): If the inferences lead to a board that can't be solved
+#[derive(Clone, Debug)]
+pub struct Branch {
+    pub assumption: Node,
+    pub inferences: Vec<Inference>,
+    pub contradiction: bool,
+}
+
+/// Category
+///
+/// The family a technique belongs to
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Category {
+    Singles,
+    Intersections,
+    Subsets,
+    Fish,
+    SingleDigitPatterns,
+    Coloring,
+    Wings,
+    Uniqueness,
+    Chains,
+    AlmostLockedSets,
+    Exclusion,
+    Forcing,
+}
+
+/// StrategyKind
+///
+/// Identifies the technique a strategy was found with. The identifier is stable, so
+/// it can be stored and compared, unlike the name that is shown to a reader
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StrategyKind {
+    NakedSingle,
+    LastRemainingCell,
+    HiddenSingle,
+    Pointing,
+    BoxLineReduction,
+    Naked,
+    Hidden,
+    Fish,
+    Skyscraper,
+    TwoStringKite,
+    FinnedFish,
+    EmptyRectangle,
+    TurbotFish,
+    Coloring,
+    XYWing,
+    WWing,
+    XYZWing,
+    UniqueRectangle,
+    WXYZWing,
+    HiddenUniqueRectangle,
+    XCycles,
+    FrankenFish,
+    SueDeCoq,
+    Medusa,
+    MutantFish,
+    AlignedPairExclusion,
+    BivalueUniversalGrave,
+    AlsXZ,
+    AlsXYWing,
+    AlignedTripleExclusion,
+    AlsChain,
+    DeathBlossom,
+    AlternatingInferenceChain,
+    KrakenFish,
+    CellForcingChain,
+    DigitForcingChain,
+    ContradictionForcingNet,
+}
+
+impl StrategyKind {
+    /// Gets every kind, from simple to complex
+    ///
+    /// ### Returns
+    ///   Vec<StrategyKind>: the kinds
+    pub fn all() -> Vec<StrategyKind> {
+        return vec![
+            StrategyKind::NakedSingle,
+            StrategyKind::LastRemainingCell,
+            StrategyKind::HiddenSingle,
+            StrategyKind::Pointing,
+            StrategyKind::BoxLineReduction,
+            StrategyKind::Naked,
+            StrategyKind::Hidden,
+            StrategyKind::Fish,
+            StrategyKind::Skyscraper,
+            StrategyKind::TwoStringKite,
+            StrategyKind::FinnedFish,
+            StrategyKind::EmptyRectangle,
+            StrategyKind::TurbotFish,
+            StrategyKind::Coloring,
+            StrategyKind::XYWing,
+            StrategyKind::WWing,
+            StrategyKind::XYZWing,
+            StrategyKind::UniqueRectangle,
+            StrategyKind::WXYZWing,
+            StrategyKind::HiddenUniqueRectangle,
+            StrategyKind::XCycles,
+            StrategyKind::FrankenFish,
+            StrategyKind::SueDeCoq,
+            StrategyKind::Medusa,
+            StrategyKind::MutantFish,
+            StrategyKind::AlignedPairExclusion,
+            StrategyKind::BivalueUniversalGrave,
+            StrategyKind::AlsXZ,
+            StrategyKind::AlsXYWing,
+            StrategyKind::AlignedTripleExclusion,
+            StrategyKind::AlsChain,
+            StrategyKind::DeathBlossom,
+            StrategyKind::AlternatingInferenceChain,
+            StrategyKind::KrakenFish,
+            StrategyKind::CellForcingChain,
+            StrategyKind::DigitForcingChain,
+            StrategyKind::ContradictionForcingNet,
+        ]
+    }
+
+    /// Gets the stable identifier of the kind
+    ///
+    /// ### Returns
+    ///   &str: the identifier, for example "HiddenSingle"
+    pub fn id(&self) -> &'static str {
+        return match self {
+            StrategyKind::NakedSingle => "NakedSingle",
+            StrategyKind::LastRemainingCell => "LastRemainingCell",
+            StrategyKind::HiddenSingle => "HiddenSingle",
+            StrategyKind::Pointing => "Pointing",
+            StrategyKind::BoxLineReduction => "BoxLineReduction",
+            StrategyKind::Naked => "Naked",
+            StrategyKind::Hidden => "Hidden",
+            StrategyKind::Fish => "Fish",
+            StrategyKind::Skyscraper => "Skyscraper",
+            StrategyKind::TwoStringKite => "TwoStringKite",
+            StrategyKind::FinnedFish => "FinnedFish",
+            StrategyKind::EmptyRectangle => "EmptyRectangle",
+            StrategyKind::TurbotFish => "TurbotFish",
+            StrategyKind::Coloring => "Coloring",
+            StrategyKind::XYWing => "XYWing",
+            StrategyKind::WWing => "WWing",
+            StrategyKind::XYZWing => "XYZWing",
+            StrategyKind::UniqueRectangle => "UniqueRectangle",
+            StrategyKind::WXYZWing => "WXYZWing",
+            StrategyKind::HiddenUniqueRectangle => "HiddenUniqueRectangle",
+            StrategyKind::XCycles => "XCycles",
+            StrategyKind::FrankenFish => "FrankenFish",
+            StrategyKind::SueDeCoq => "SueDeCoq",
+            StrategyKind::Medusa => "Medusa",
+            StrategyKind::MutantFish => "MutantFish",
+            StrategyKind::AlignedPairExclusion => "AlignedPairExclusion",
+            StrategyKind::BivalueUniversalGrave => "BivalueUniversalGrave",
+            StrategyKind::AlsXZ => "AlsXZ",
+            StrategyKind::AlsXYWing => "AlsXYWing",
+            StrategyKind::AlignedTripleExclusion => "AlignedTripleExclusion",
+            StrategyKind::AlsChain => "AlsChain",
+            StrategyKind::DeathBlossom => "DeathBlossom",
+            StrategyKind::AlternatingInferenceChain => "AlternatingInferenceChain",
+            StrategyKind::KrakenFish => "KrakenFish",
+            StrategyKind::CellForcingChain => "CellForcingChain",
+            StrategyKind::DigitForcingChain => "DigitForcingChain",
+            StrategyKind::ContradictionForcingNet => "ContradictionForcingNet",
+        }
+    }
+
+    /// Gets the name of the kind as it is shown to a reader
+    ///
+    /// ### Returns
+    ///   &str: the name, for example "Hidden Single"
+    pub fn display_name(&self) -> &'static str {
+        return match self {
+            StrategyKind::NakedSingle => "Naked Single",
+            StrategyKind::LastRemainingCell => "Last Remaining Cell",
+            StrategyKind::HiddenSingle => "Hidden Single",
+            StrategyKind::Pointing => "Pointing",
+            StrategyKind::BoxLineReduction => "Box Line Reduction",
+            StrategyKind::Naked => "Naked",
+            StrategyKind::Hidden => "Hidden",
+            StrategyKind::Fish => "Fish",
+            StrategyKind::Skyscraper => "Skyscraper",
+            StrategyKind::TwoStringKite => "Two String Kite",
+            StrategyKind::FinnedFish => "Finned Fish",
+            StrategyKind::EmptyRectangle => "Empty Rectangle",
+            StrategyKind::TurbotFish => "Turbot Fish",
+            StrategyKind::Coloring => "Coloring",
+            StrategyKind::XYWing => "XY-Wing",
+            StrategyKind::WWing => "W-Wing",
+            StrategyKind::XYZWing => "XYZ-Wing",
+            StrategyKind::UniqueRectangle => "Unique Rectangle",
+            StrategyKind::WXYZWing => "WXYZ-Wing",
+            StrategyKind::HiddenUniqueRectangle => "Hidden Unique Rectangle",
+            StrategyKind::XCycles => "X-Cycles",
+            StrategyKind::FrankenFish => "Franken Fish",
+            StrategyKind::SueDeCoq => "Sue de Coq",
+            StrategyKind::Medusa => "3D Medusa",
+            StrategyKind::MutantFish => "Mutant Fish",
+            StrategyKind::AlignedPairExclusion => "Aligned Pair Exclusion",
+            StrategyKind::BivalueUniversalGrave => "Bivalue Universal Grave",
+            StrategyKind::AlsXZ => "ALS-XZ",
+            StrategyKind::AlsXYWing => "ALS-XY-Wing",
+            StrategyKind::AlignedTripleExclusion => "Aligned Triple Exclusion",
+            StrategyKind::AlsChain => "ALS Chain",
+            StrategyKind::DeathBlossom => "Death Blossom",
+            StrategyKind::AlternatingInferenceChain => "Alternating Inference Chain",
+            StrategyKind::KrakenFish => "Kraken Fish",
+            StrategyKind::CellForcingChain => "Cell Forcing Chain",
+            StrategyKind::DigitForcingChain => "Digit Forcing Chain",
+            StrategyKind::ContradictionForcingNet => "Contradiction Forcing Net",
+        }
+    }
+
+    /// Gets the default difficulty weight of the kind, a higher weight means a
+    /// harder technique
+    ///
+    /// ### Returns
+    ///   u32: the weight
+    pub fn weight(&self) -> u32 {
+        return match self {
+            StrategyKind::NakedSingle => 10,
+            StrategyKind::LastRemainingCell => 10,
+            StrategyKind::HiddenSingle => 12,
+            StrategyKind::Pointing => 26,
+            StrategyKind::BoxLineReduction => 28,
+            StrategyKind::Naked => 30,
+            StrategyKind::Hidden => 34,
+            StrategyKind::Fish => 38,
+            StrategyKind::Skyscraper => 39,
+            StrategyKind::TwoStringKite => 39,
+            StrategyKind::FinnedFish => 40,
+            StrategyKind::EmptyRectangle => 40,
+            StrategyKind::TurbotFish => 40,
+            StrategyKind::Coloring => 41,
+            StrategyKind::XYWing => 42,
+            StrategyKind::WWing => 43,
+            StrategyKind::XYZWing => 44,
+            StrategyKind::UniqueRectangle => 45,
+            StrategyKind::WXYZWing => 46,
+            StrategyKind::HiddenUniqueRectangle => 47,
+            StrategyKind::XCycles => 48,
+            StrategyKind::FrankenFish => 49,
+            StrategyKind::SueDeCoq => 50,
+            StrategyKind::Medusa => 52,
+            StrategyKind::MutantFish => 53,
+            StrategyKind::AlignedPairExclusion => 54,
+            StrategyKind::BivalueUniversalGrave => 56,
+            StrategyKind::AlsXZ => 58,
+            StrategyKind::AlsXYWing => 60,
+            StrategyKind::AlignedTripleExclusion => 62,
+            StrategyKind::AlsChain => 64,
+            StrategyKind::DeathBlossom => 65,
+            StrategyKind::AlternatingInferenceChain => 66,
+            StrategyKind::KrakenFish => 68,
+            StrategyKind::CellForcingChain => 70,
+            StrategyKind::DigitForcingChain => 72,
+            StrategyKind::ContradictionForcingNet => 76,
+        }
+    }
+
+    /// Gets the family of techniques the kind belongs to
+    ///
+    /// ### Returns
+    ///   Category: the category
+    pub fn category(&self) -> Category {
+        return match self {
+            StrategyKind::NakedSingle
+            | StrategyKind::LastRemainingCell
+            | StrategyKind::HiddenSingle => Category::Singles,
+            StrategyKind::Pointing
+            | StrategyKind::BoxLineReduction
+            | StrategyKind::SueDeCoq => Category::Intersections,
+            StrategyKind::Naked
+            | StrategyKind::Hidden => Category::Subsets,
+            StrategyKind::Fish
+            | StrategyKind::FinnedFish
+            | StrategyKind::FrankenFish
+            | StrategyKind::MutantFish
+            | StrategyKind::KrakenFish => Category::Fish,
+            StrategyKind::Skyscraper
+            | StrategyKind::TwoStringKite
+            | StrategyKind::EmptyRectangle
+            | StrategyKind::TurbotFish => Category::SingleDigitPatterns,
+            StrategyKind::Coloring
+            | StrategyKind::Medusa => Category::Coloring,
+            StrategyKind::XYWing
+            | StrategyKind::WWing
+            | StrategyKind::XYZWing
+            | StrategyKind::WXYZWing => Category::Wings,
+            StrategyKind::UniqueRectangle
+            | StrategyKind::HiddenUniqueRectangle
+            | StrategyKind::BivalueUniversalGrave => Category::Uniqueness,
+            StrategyKind::XCycles
+            | StrategyKind::AlternatingInferenceChain => Category::Chains,
+            StrategyKind::AlsXZ
+            | StrategyKind::AlsXYWing
+            | StrategyKind::AlsChain
+            | StrategyKind::DeathBlossom => Category::AlmostLockedSets,
+            StrategyKind::AlignedPairExclusion
+            | StrategyKind::AlignedTripleExclusion => Category::Exclusion,
+            StrategyKind::CellForcingChain
+            | StrategyKind::DigitForcingChain
+            | StrategyKind::ContradictionForcingNet => Category::Forcing,
+        }
+    }
+}
+
+/// MedusaRule
+///
+/// Identifies the rule of 3D Medusa that a strategy was found with
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MedusaRule {
+    ColorTwiceInCell,
+    ColorTwiceInHouse,
+    TwoColorsInCell,
+    TwoColorsElsewhere,
+    TwoColorsUnitAndCell,
+    CellEmptiedByColor,
+}
+
+impl MedusaRule {
+    /// Gets the name of the rule as it is shown to a reader
+    ///
+    /// ### Returns
+    ///   &str: the name, for example "Color Twice In Cell"
+    pub fn display_name(&self) -> &'static str {
+        return match self {
+            MedusaRule::ColorTwiceInCell => "Color Twice In Cell",
+            MedusaRule::ColorTwiceInHouse => "Color Twice In House",
+            MedusaRule::TwoColorsInCell => "Two Colors In Cell",
+            MedusaRule::TwoColorsElsewhere => "Two Colors Elsewhere",
+            MedusaRule::TwoColorsUnitAndCell => "Two Colors Unit And Cell",
+            MedusaRule::CellEmptiedByColor => "Cell Emptied By Color",
+        }
+    }
+}
+
+/// ### Strategy
+///
+/// Contains the kind of the strategy and a hashmap with the
+/// index of the cell and the probabilities that should be removed from the cell.
+/// A strategy can also place values, which removes the other probabilities of the cell
+///
+/// The strategy represents a step in the solving process of a sudoku puzzle
+///
+/// ### Attributes
+///    kind (StrategyKind): The technique the strategy was found with
+///    remove (HashMap<u8, HashSet<u8>>): The index of the cell and the probabilities that
+///    place (HashMap<u8, u8>): The index of the cell and the value that should be placed
+///    base (Vec<House>): The houses the pattern is based on, used by fish
+///    cover (Vec<House>): The houses that cover the base, used by fish
+///    fins (Vec<u8>): The index of the cells that are fins of the pattern
+///    chain (Vec<Link>): The links of the chain the strategy is based on
+///    branches (Vec<Branch>): The assumptions the strategy is based on, used by forcing strategies
+///    rule (Option<MedusaRule>): The rule of the strategy that was used, for 3D Medusa
+///    cells (Vec<u8>): The index of the cells that form the pattern, sorted
+///    digits (Vec<u8>): The digits the pattern is about, sorted
+pub struct Strategy {
+    pub kind: StrategyKind,
+    pub remove: HashMap<u8, HashSet<u8>>,
+    pub place: HashMap<u8, u8>,
+    pub base: Vec<House>,
+    pub cover: Vec<House>,
+    pub fins: Vec<u8>,
+    pub chain: Vec<Link>,
+    pub branches: Vec<Branch>,
+    pub rule: Option<MedusaRule>,
+    pub cells: Vec<u8>,
+    pub digits: Vec<u8>,
+}
+
+impl Strategy {
+    /// Creates a new strategy
+    ///
+    /// ### Arguments
+    ///    kind (StrategyKind): The technique the strategy was found with
+    ///    remove (HashMap<u8, HashSet<u8>>): The index of the cell and the probabilities that
+    ///     should be removed
+    pub fn new(kind: StrategyKind, remove: HashMap<u8, HashSet<u8>>) -> Strategy {
+        return Strategy {
+            kind,
+            remove,
+            place: HashMap::new(),
+            base: vec![],
+            cover: vec![],
+            fins: vec![],
+            chain: vec![],
+            branches: vec![],
+            rule: None,
+            cells: vec![],
+            digits: vec![],
+        }
+    }
+
+    /// Records the values the strategy places
+    ///
+    /// ### Arguments
+    ///    place (HashMap<u8, u8>): The index of the cell and the value
+    ///
+    /// ### Returns
+    ///    Strategy: the strategy with the placements
+    pub fn with_place(mut self, place: HashMap<u8, u8>) -> Strategy {
+        self.place = place;
+        return self
+    }
+
+    /// Records the base and cover houses of the pattern
+    ///
+    /// ### Arguments
+    ///    base (Vec<House>): The houses the pattern is based on
+    ///    cover (Vec<House>): The houses that cover the base
+    ///
+    /// ### Returns
+    ///    Strategy: the strategy with the houses
+    pub fn with_houses(mut self, base: Vec<House>, cover: Vec<House>) -> Strategy {
+        self.base = base;
+        self.cover = cover;
+        return self
+    }
+
+    /// Records the fins of the pattern
+    ///
+    /// ### Arguments
+    ///    fins (Vec<u8>): The index of the cells that are fins
+    ///
+    /// ### Returns
+    ///    Strategy: the strategy with the fins
+    pub fn with_fins(mut self, fins: Vec<u8>) -> Strategy {
+        self.fins = fins;
+        return self
+    }
+
+    /// Records the chain the strategy is based on
+    ///
+    /// ### Arguments
+    ///    chain (Vec<Link>): The links of the chain, in order
+    ///
+    /// ### Returns
+    ///    Strategy: the strategy with the chain
+    pub fn with_chain(mut self, chain: Vec<Link>) -> Strategy {
+        self.chain = chain;
+        return self
+    }
+
+    /// Records the assumptions the strategy is based on
+    ///
+    /// ### Arguments
+    ///    branches (Vec<Branch>): The assumptions and their inferences
+    ///
+    /// ### Returns
+    ///    Strategy: the strategy with the branches
+    pub fn with_branches(mut self, branches: Vec<Branch>) -> Strategy {
+        self.branches = branches;
+        return self
+    }
+
+    /// Records the rule of the strategy that was used
+    ///
+    /// ### Arguments
+    ///    rule (MedusaRule): The rule
+    ///
+    /// ### Returns
+    ///    Strategy: the strategy with the rule
+    pub fn with_rule(mut self, rule: MedusaRule) -> Strategy {
+        self.rule = Some(rule);
+        return self
+    }
+
+    /// Records the cells that form the pattern and the digits it is about
+    ///
+    /// ### Arguments
+    ///    cells (Vec<u8>): The index of the cells
+    ///    digits (Vec<u8>): The digits
+    ///
+    /// ### Returns
+    ///    Strategy: the strategy with the pattern
+    pub fn with_pattern(mut self, mut cells: Vec<u8>, mut digits: Vec<u8>) -> Strategy {
+        cells.sort();
+        cells.dedup();
+        digits.sort();
+        digits.dedup();
+
+        self.cells = cells;
+        self.digits = digits;
+        return self
+    }
+
+    /// Explains the strategy in the row/column notation, for example
+    /// "Pointing: 3 in block 1 is locked to row 2, remove 3 from r2c4, r2c5"
+    ///
+    /// The pattern is described by its houses when it has base and cover houses,
+    /// otherwise by its cells. The chain and the assumptions follow, and the
+    /// removals are grouped by digit
+    ///
+    /// ### Returns
+    ///    String: the explanation
+    pub fn explanation(&self) -> String {
+        let mut parts: Vec<String> = vec![];
+        let digits: String = self.digits.iter().map(|d| d.to_string()).collect::<Vec<String>>().join("/");
+        let houses = |houses: &[House]| -> String {
+            houses.iter().map(|h| h.name()).collect::<Vec<String>>().join(", ")
+        };
+
+        if !self.base.is_empty() && !self.cover.is_empty() {
+            parts.push(format!("{} in {} is locked to {}", digits, houses(&self.base), houses(&self.cover)));
+        } else if !self.cells.is_empty() && !self.base.is_empty() {
+            parts.push(format!("{} in {} of {}", digits, cell_names(&self.cells), houses(&self.base)));
+        } else if !self.cells.is_empty() {
+            parts.push(format!("{} in {}", digits, cell_names(&self.cells)));
+        }
+
+        if !self.fins.is_empty() {
+            parts.push(format!("with fins {}", cell_names(&self.fins)));
+        }
+
+        if let Some(first) = self.chain.first() {
+            let mut chain: String = first.from.name();
+            for link in &self.chain {
+                chain.push_str(&format!(" {} {}", if link.strong {"="} else {"-"}, link.to.name()));
+            }
+            parts.push(format!("chain {}", chain));
+        }
+
+        let assumptions: Vec<String> = self.branches
+            .iter()
+            .filter(|b| !b.contradiction)
+            .map(|b| b.assumption.name())
+            .collect();
+
+        if !assumptions.is_empty() {
+            parts.push(format!("assuming any of {} leads to the removals", assumptions.join(", ")));
+        }
+
+        for branch in self.branches.iter().filter(|b| b.contradiction) {
+            parts.push(format!("assuming {} leads to a contradiction", branch.assumption.name()));
+        }
+
+        // The removals are grouped by digit, the cells are sorted
+        let mut removals: HashMap<u8, Vec<u8>> = HashMap::new();
+        for (i, probabilities) in &self.remove {
+            for d in probabilities {
+                removals.entry(*d).or_default().push(*i);
+            }
+        }
+
+        let mut removed: Vec<(u8, Vec<u8>)> = removals.into_iter().collect();
+        removed.sort();
+
+        if !removed.is_empty() {
+            parts.push(format!(
+                "remove {}",
+                removed
+                    .iter()
+                    .map(|(d, cells)| format!("{} from {}", d, cell_names(cells)))
+                    .collect::<Vec<String>>()
+                    .join(" and ")
+            ));
+        }
+
+        let mut placed: Vec<(&u8, &u8)> = self.place.iter().collect();
+        placed.sort();
+
+        for (i, value) in placed {
+            parts.push(format!("place {} in {}", value, cell_name(*i)));
+        }
+
+        let rule: String = self.rule.map(|r| format!(" ({})", r.display_name())).unwrap_or_default();
+        return format!("{}{}: {}", self.kind.display_name(), rule, parts.join(", "))
+    }
+
+    /// Prints the strategy when it has removals or placements
+    /// Used for debugging
+    ///
+    /// The strategy will be printed with the name and the cells that will be removed.
+    /// Only prints if there are removals or placements
+    pub fn print(&self) {
+        if self.remove.is_empty() && self.place.is_empty() {
+            return
+        }
+
+        println!("Strategy: {:?}", self.kind);
+        if let Some(rule) = self.rule {
+            println!("Rule: {:?}", rule);
+        }
+        if !self.base.is_empty() {
+            println!("Base {:?}, cover {:?}, fins {:?}", self.base, self.cover, self.fins);
+        }
+        for link in &self.chain {
+            println!(
+                "{:?} {:?} {} {:?} {:?}",
+                link.from.digit, link.from.cells, if link.strong {"="} else {"-"}, link.to.digit, link.to.cells
+            );
+        }
+        for branch in &self.branches {
+            println!(
+                "Assume {:?} in {:?}{}",
+                branch.assumption.digit, branch.assumption.cells, if branch.contradiction {", contradiction"} else {""}
+            );
+            for inference in &branch.inferences {
+                println!("    {:?} removes {:?}", inference.kind, inference.remove);
+            }
+        }
+        for (i, p) in &self.remove {
+            println!("For cell {:?}, will remove {:?}", i, p);
+        }
+        for (i, value) in &self.place {
+            println!("For cell {:?}, will place {:?}", i, value);
+        }
+    }
+}
+
+
+/// Get the name of a cell, with the row and column counted from one
+///
+/// For example, cell index 10 corresponds to r2c2
+///
+/// ### Arguments
+///    index (u8): the index of the cell
+///
+/// ### Returns
+///    String: the name of the cell
+pub fn cell_name(index: u8) -> String {
+    return format!("r{}c{}", index / 9 + 1, index % 9 + 1)
+}
+
+
+/// Get the names of cells, sorted by index and separated by commas
+///
+/// ### Arguments
+///    indices (&[u8]): the index of the cells
+///
+/// ### Returns
+///    String: the names of the cells
+pub fn cell_names(indices: &[u8]) -> String {
+    let mut indices: Vec<u8> = indices.to_vec();
+    indices.sort();
+    return indices.iter().map(|i| cell_name(*i)).collect::<Vec<String>>().join(", ")
+}
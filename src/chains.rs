@@ -0,0 +1,461 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use crate::board::*;
+use crate::solvers::*;
+
+use itertools::Itertools;
+
+
+/// ### LinkGraph
+///
+/// Contains the nodes of a board and the strong and weak links between them.
+/// A node is strongly linked to another node when at least one of them is true,
+/// and weakly linked when at most one of them is true. Every strong link between
+/// two nodes that exclude each other is also a weak link.
+///
+/// Strong links come from houses in which a digit can only go in two nodes and
+/// from cells with two probabilities. Weak links come from nodes with the same
+/// digit that see each other and from the probabilities of a single cell.
+///
+/// ### Attributes
+///    nodes (Vec<Node>): The nodes of the graph
+///    strong (Vec<Vec<usize>>): For every node, the nodes it is strongly linked to
+///    weak (Vec<Vec<usize>>): For every node, the nodes it is weakly linked to
+pub struct LinkGraph {
+    pub nodes: Vec<Node>,
+    pub strong: Vec<Vec<usize>>,
+    pub weak: Vec<Vec<usize>>,
+}
+
+impl LinkGraph {
+    /// Creates the graph for the given digits of the board
+    ///
+    /// ### Arguments
+    ///    board (&Board): the sudoku
+    ///    digits (&[u8]): the digits of which the nodes are added
+    ///    grouped (bool): if grouped nodes should be added
+    ///
+    /// ### Returns
+    ///    LinkGraph: the graph
+    pub fn new(board: &Board, digits: &[u8], grouped: bool) -> LinkGraph {
+        let mut graph: LinkGraph = LinkGraph {
+            nodes: vec![],
+            strong: vec![],
+            weak: vec![],
+        };
+
+        for digit in digits {
+            for c in board.cells.iter().filter(|c| !c.solved() && c.contains(digit)) {
+                graph.add_node(Node { cells: vec![c.index], digit: *digit });
+            }
+
+            if !grouped {
+                continue
+            }
+
+            for block in 0..9 {
+                for line in block_lines(board, block, *digit) {
+                    if line.len() > 1 {
+                        graph.add_node(Node { cells: line, digit: *digit });
+                    }
+                }
+            }
+        }
+
+        // A grouped node of a strong link can be a part of a line of a block
+        for digit in digits {
+            for cells in strong_splits(board, *digit, grouped) {
+                let nodes: Vec<usize> = cells
+                    .into_iter()
+                    .map(|cells| graph.find_or_add(Node { cells, digit: *digit }))
+                    .collect();
+                graph.add_link(nodes[0], nodes[1], true);
+            }
+        }
+
+        let index: HashMap<Node, usize> = graph.nodes
+            .iter()
+            .cloned()
+            .enumerate()
+            .map(|(i, n)| (n, i))
+            .collect();
+
+        for c in board.cells.iter().filter(|c| !c.solved()) {
+            let probable: Vec<u8> = c.probabilities
+                .iter()
+                .filter(|d| digits.contains(d))
+                .copied()
+                .collect();
+
+            for (a, b) in probable.iter().tuple_combinations() {
+                graph.add_link(
+                    index[&Node { cells: vec![c.index], digit: *a }],
+                    index[&Node { cells: vec![c.index], digit: *b }],
+                    c.probabilities.len() == 2
+                );
+            }
+        }
+
+        for (a, b) in (0..graph.nodes.len()).tuple_combinations() {
+            if graph.nodes[a].digit == graph.nodes[b].digit
+                && weakly_linked(board, &graph.nodes[a], &graph.nodes[b]) {
+                graph.add_link(a, b, false);
+            }
+        }
+
+        return graph
+    }
+
+    /// Adds a node to the graph, without any links
+    fn add_node(&mut self, node: Node) {
+        self.nodes.push(node);
+        self.strong.push(vec![]);
+        self.weak.push(vec![]);
+    }
+
+    /// Links two nodes in both directions. Strongly linked nodes always exclude each
+    /// other, so a strong link is added as a weak link too
+    fn add_link(&mut self, a: usize, b: usize, strong: bool) {
+        if strong && !self.strong[a].contains(&b) {
+            self.strong[a].push(b);
+            self.strong[b].push(a);
+        }
+        if !self.weak[a].contains(&b) {
+            self.weak[a].push(b);
+            self.weak[b].push(a);
+        }
+    }
+
+    /// Gets the node with the given cells and digit, the node is added when it isn't
+    /// part of the graph yet
+    fn find_or_add(&mut self, node: Node) -> usize {
+        return match self.find(&node) {
+            Some(i) => i,
+            None => {
+                self.add_node(node);
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    /// Gets the node with the given cells and digit
+    ///
+    /// ### Returns
+    ///    Option<usize>: the index of the node, None if it isn't part of the graph
+    pub fn find(&self, node: &Node) -> Option<usize> {
+        return self.nodes.iter().position(|n| n == node)
+    }
+
+    /// Follows the alternating inference chains that start with a strong link from
+    /// a node. When the node is false, the first link makes the next node true, a weak
+    /// link makes the node after it false, and so on. For every node that becomes true,
+    /// the shortest chain is returned. Either the first or the last node of such a
+    /// chain is true.
+    ///
+    /// ### Arguments
+    ///    start (usize): the index of the node the chains start from
+    ///
+    /// ### Returns
+    ///    Vec<(usize, Vec<Link>)>: the index of the last node and the links of the chain
+    pub fn chains(&self, start: usize) -> Vec<(usize, Vec<Link>)> {
+        let mut parent: HashMap<(usize, bool), (usize, bool)> = HashMap::from([((start, false), (start, false))]);
+        let mut queue: VecDeque<(usize, bool)> = VecDeque::from([(start, false)]);
+        let mut ends: Vec<usize> = vec![];
+
+        while let Some((node, on)) = queue.pop_front() {
+            let next: &Vec<usize> = if on { &self.weak[node] } else { &self.strong[node] };
+
+            for n in next {
+                if parent.contains_key(&(*n, !on)) {
+                    continue
+                }
+
+                parent.insert((*n, !on), (node, on));
+                queue.push_back((*n, !on));
+
+                if !on {
+                    ends.push(*n);
+                }
+            }
+        }
+
+        return ends
+            .into_iter()
+            .map(|end| {
+                let mut links: Vec<Link> = vec![];
+                let mut state: (usize, bool) = (end, true);
+
+                while state != (start, false) {
+                    let previous: (usize, bool) = parent[&state];
+                    links.push(Link {
+                        from: self.nodes[previous.0].clone(),
+                        to: self.nodes[state.0].clone(),
+                        strong: state.1,
+                    });
+                    state = previous;
+                }
+
+                links.reverse();
+                (end, links)
+            })
+            .collect()
+    }
+}
+
+
+/// Checks if at most one of two nodes can be true. That is the case when the nodes
+/// have the same digit and all their cells see each other, or when both nodes are
+/// the same single cell with different digits
+///
+/// ### Arguments
+///   board (&Board): the sudoku
+///   a (&Node): the first node
+///   b (&Node): the second node
+///
+/// ### Returns
+///   bool: true if the nodes are weakly linked
+pub fn weakly_linked(board: &Board, a: &Node, b: &Node) -> bool {
+    if a.digit != b.digit {
+        return a.cells.len() == 1 && a.cells == b.cells
+    }
+
+    return a.cells
+        .iter()
+        .all(|i| b.cells.iter().all(|j| board.cells[*i as usize].sees(&board.cells[*j as usize])))
+}
+
+
+/// Gets the cells of a block in which a digit is probable, split by the rows and
+/// columns of the block. Lines without the digit are left out
+///
+/// ### Arguments
+///   board (&Board): the sudoku
+///   block (u8): the index of the block
+///   digit (u8): the digit
+///
+/// ### Returns
+///   Vec<Vec<u8>>: the index of the cells, for every line
+fn block_lines(board: &Board, block: u8, digit: u8) -> Vec<Vec<u8>> {
+    let cells: Vec<Cell> = board.block(block)
+        .missing()
+        .into_iter()
+        .filter(|c| c.contains(&digit))
+        .collect();
+
+    let rows = cells.iter().map(|c| c.row()).unique().map(|r| cells.iter().filter(|c| c.row() == r).map(|c| c.index).collect());
+    let columns = cells.iter().map(|c| c.column()).unique().map(|r| cells.iter().filter(|c| c.column() == r).map(|c| c.index).collect());
+
+    return rows.chain(columns).collect()
+}
+
+
+/// Gets the houses in which the cells of a digit can be split into two nodes. One
+/// of both nodes has to contain the digit. Without grouped nodes, these are the
+/// conjugate pairs of the digit
+///
+/// ### Arguments
+///   board (&Board): the sudoku
+///   digit (u8): the digit
+///   grouped (bool): if a node may consist of multiple cells
+///
+/// ### Returns
+///   Vec<[Vec<u8>; 2]>: the sorted index of the cells of both nodes
+fn strong_splits(board: &Board, digit: u8, grouped: bool) -> Vec<[Vec<u8>; 2]> {
+    let mut splits: Vec<[Vec<u8>; 2]> = vec![];
+
+    // A node is a single cell, or cells of a block that share a row or column
+    let is_node = |cells: &Vec<&Cell>| -> bool {
+        return cells.len() == 1 || (grouped && cells.iter().map(|c| c.block()).all_equal() && (
+            cells.iter().map(|c| c.row()).all_equal() || cells.iter().map(|c| c.column()).all_equal()
+        ))
+    };
+
+    for i in 0..9 {
+        for (orientation, subset) in [
+            (Orientation::Row, board.row(i)),
+            (Orientation::Column, board.column(i)),
+            (Orientation::Block, board.block(i)),
+        ] {
+            if subset.contains(&digit) {
+                continue
+            }
+
+            let cells: Vec<Cell> = subset.missing()
+                .into_iter()
+                .filter(|c| c.contains(&digit))
+                .collect();
+
+            // The line that divides the cells, for a block its rows and columns and
+            // for a row or column the blocks it crosses
+            let line: [fn(&Cell) -> u8; 2] = match orientation {
+                Orientation::Block => [|c: &Cell| c.row(), |c: &Cell| c.column()],
+                _ => [|c: &Cell| c.block(), |c: &Cell| c.block()],
+            };
+
+            for line in line {
+                for l in cells.iter().map(line).unique() {
+                    let (a, b): (Vec<&Cell>, Vec<&Cell>) = cells.iter().partition(|c| line(c) == l);
+
+                    if b.is_empty() || !is_node(&a) || !is_node(&b) {
+                        continue
+                    }
+
+                    let mut split: [Vec<u8>; 2] = [
+                        a.iter().map(|c| c.index).sorted().collect(),
+                        b.iter().map(|c| c.index).sorted().collect(),
+                    ];
+                    split.sort();
+
+                    if !splits.contains(&split) {
+                        splits.push(split);
+                    }
+                }
+            }
+        }
+    }
+
+    return splits
+}
+
+
+/// Gets the strategies of the chains in a graph that start with a node of a digit.
+/// Either the first or the last node of a chain is true, so every probability that
+/// is weakly linked to both ends is removed. When the ends are weakly linked as well,
+/// the chain is a loop in which every link holds exactly one true node. Then every
+/// probability that is weakly linked to both nodes of any link is removed.
+///
+/// ### Arguments
+///   board (&Board): the sudoku
+///   graph (&LinkGraph): the graph of the board
+///   digit (u8): the digit of the first node
+///   kind (StrategyKind): the kind of the strategy
+///
+/// ### Returns
+///   Vec<Strategy>: a strategy for every chain, the shortest chain for every elimination
+fn chain_strategies(board: &Board, graph: &LinkGraph, digit: u8, kind: StrategyKind) -> Vec<Strategy> {
+    let mut strategies: Vec<Strategy> = vec![];
+    let probable: Vec<Node> = board.cells
+        .iter()
+        .filter(|c| !c.solved())
+        .flat_map(|c| c.probabilities.iter().map(|d| Node { cells: vec![c.index], digit: *d }))
+        .collect();
+
+    // Gets the probabilities that are weakly linked to both nodes
+    let linked = |candidates: &Vec<&Node>, a: &Node, b: &Node| -> HashMap<u8, HashSet<u8>> {
+        let mut hashmap: HashMap<u8, HashSet<u8>> = HashMap::new();
+        for n in candidates {
+            if weakly_linked(board, n, a) && weakly_linked(board, n, b) {
+                hashmap.entry(n.cells[0]).or_default().insert(n.digit);
+            }
+        }
+        return hashmap
+    };
+
+    let all: Vec<&Node> = probable.iter().collect();
+    let mut found: HashSet<Vec<(u8, u8)>> = HashSet::new();
+
+    for start in (0..graph.nodes.len()).filter(|n| graph.nodes[*n].digit == digit) {
+        let first: &Node = &graph.nodes[start];
+        let near: Vec<&Node> = probable
+            .iter()
+            .filter(|n| weakly_linked(board, n, first))
+            .collect();
+
+        for (end, mut chain) in graph.chains(start) {
+            let last: &Node = &graph.nodes[end];
+            let mut hashmap: HashMap<u8, HashSet<u8>> = linked(&near, first, last);
+
+            if end != start && weakly_linked(board, last, first) {
+                chain.push(Link { from: last.clone(), to: first.clone(), strong: false });
+
+                for link in &chain {
+                    for (i, p) in linked(&all, &link.from, &link.to) {
+                        hashmap.entry(i).or_default().extend(p);
+                    }
+                }
+            }
+
+            if hashmap.is_empty() {
+                continue
+            }
+
+            let key: Vec<(u8, u8)> = hashmap
+                .iter()
+                .flat_map(|(i, p)| p.iter().map(|d| (*i, *d)))
+                .sorted()
+                .collect();
+
+            if !found.insert(key) {
+                continue
+            }
+
+            let cells: Vec<u8> = chain.iter().flat_map(|l| l.from.cells.iter().chain(l.to.cells.iter()).copied()).collect();
+            let digits: Vec<u8> = chain.iter().flat_map(|l| [l.from.digit, l.to.digit]).collect();
+
+            strategies.push(
+                Strategy::new(
+                    kind,
+                    hashmap
+                ).with_chain(chain)
+                .with_pattern(cells, digits)
+            );
+        }
+    }
+
+    return strategies
+}
+
+
+/// AlternatingInferenceChain
+///
+/// Follows chains of alternating strong and weak links, where a node is either a
+/// single probability or a group of cells in a block that share a row or column.
+/// Since either the first or the last node of such a chain is true, every
+/// probability that is weakly linked to both ends can be removed. That covers the
+/// discontinuous nice loops, where both ends are the same node.
+///
+/// When the ends of the chain are weakly linked as well, the chain is a continuous
+/// nice loop. Every link of the loop then holds exactly one true node, so every
+/// probability that is weakly linked to both nodes of a link can be removed.
+///
+/// The links of all digits are built once, every digit is tried as the digit of the
+/// first node
+pub struct AlternatingInferenceChain;
+
+impl SolveProbabilities for AlternatingInferenceChain {
+    fn kind() -> StrategyKind {
+        return StrategyKind::AlternatingInferenceChain
+    }
+
+    fn get_strategies(board: &Board) -> Vec<Strategy> {
+        let graph: LinkGraph = LinkGraph::new(board, &[1, 2, 3, 4, 5, 6, 7, 8, 9], true);
+
+        return (1..=9)
+            .flat_map(|digit| chain_strategies(board, &graph, digit, Self::kind()))
+            .collect()
+    }
+}
+
+
+/// XCycles
+///
+/// Follows chains of a single digit, the strong links are conjugate pairs and the
+/// weak links are cells that see each other. A discontinuous loop with two strong
+/// links at the discontinuity places the digit there, so the other probabilities of
+/// the cell are removed. With two weak links the digit is removed from that cell.
+/// A continuous loop removes the digit from every cell that sees both cells of a
+/// weak link of the loop.
+///
+/// Every digit is searched on its own
+pub struct XCycles;
+
+impl SolveProbabilities for XCycles {
+    fn kind() -> StrategyKind {
+        return StrategyKind::XCycles
+    }
+
+    fn get_strategies(board: &Board) -> Vec<Strategy> {
+        return (1..=9)
+            .flat_map(|digit| chain_strategies(board, &LinkGraph::new(board, &[digit], false), digit, Self::kind()))
+            .collect()
+    }
+}
@@ -0,0 +1,292 @@
+use std::collections::{HashMap, HashSet};
+use crate::board::*;
+use crate::pipeline::*;
+use crate::solvers::*;
+
+
+/// Gets the solver that is used to follow an assumption. It only contains the
+/// strategies that follow a single digit or a single cell, so an assumption can
+/// be followed quickly
+pub fn propagation() -> Solver {
+    return Solver::new()
+        .with::<LastRemainingCell>()
+        .with::<HiddenSingle>()
+        .with::<Pointing>()
+        .with::<BoxLineReduction>()
+}
+
+
+/// Places a digit in a cell and follows the consequences
+///
+/// ### Arguments
+///   board (&Board): the sudoku
+///   cell (u8): the index of the cell
+///   digit (u8): the digit that is assumed
+///
+/// ### Returns
+///   (Branch, Board): the assumption with its inferences, and the board after them
+fn assume(board: &Board, cell: u8, digit: u8) -> (Branch, Board) {
+    let mut assumed: Board = board.clone();
+    assumed.cells[cell as usize].probabilities.retain(|d| *d == digit);
+
+    let result: SolveResult = propagation().solve(assumed);
+    let branch: Branch = Branch {
+        assumption: Node { cells: vec![cell], digit },
+        inferences: result.log.steps
+            .iter()
+            .map(|s| Inference { kind: s.kind, remove: s.removed.clone() })
+            .collect(),
+        contradiction: result.status == SolveStatus::Contradiction,
+    };
+
+    return (branch, result.board)
+}
+
+
+/// Gets the probabilities of the board that are removed in all outcomes
+///
+/// ### Arguments
+///   board (&Board): the sudoku
+///   outcomes (&[Board]): the boards after every assumption
+///
+/// ### Returns
+///   HashMap<u8, HashSet<u8>>: the probabilities that can be removed
+fn removed_in_all(board: &Board, outcomes: &[&Board]) -> HashMap<u8, HashSet<u8>> {
+    return board.cells
+        .iter()
+        .filter(|c| !c.solved())
+        .map(|c| (
+            c.index,
+            c.as_set()
+                .into_iter()
+                .filter(|d| outcomes.iter().all(|o| !o.cells[c.index as usize].contains(d)))
+                .collect::<HashSet<u8>>()
+        ))
+        .filter(|(_, p)| !p.is_empty())
+        .collect()
+}
+
+
+/// Follows every alternative of a forcing chain. Alternatives that lead to a
+/// contradiction can't be true and are left out of the outcomes
+///
+/// ### Returns
+///   Option<Strategy>: the strategy, None when the alternatives have nothing in common
+fn forcing_strategy(board: &Board, alternatives: Vec<(u8, u8)>, kind: StrategyKind) -> Option<Strategy> {
+    let (branches, outcomes): (Vec<Branch>, Vec<Board>) = alternatives
+        .into_iter()
+        .map(|(cell, digit)| assume(board, cell, digit))
+        .unzip();
+
+    let valid: Vec<&Board> = outcomes
+        .iter()
+        .zip(branches.iter())
+        .filter(|(_, b)| !b.contradiction)
+        .map(|(o, _)| o)
+        .collect();
+
+    if valid.is_empty() {
+        return None
+    }
+
+    let hashmap: HashMap<u8, HashSet<u8>> = removed_in_all(board, &valid);
+
+    if hashmap.is_empty() {
+        return None
+    }
+
+    let cells: Vec<u8> = branches.iter().flat_map(|b| b.assumption.cells.iter().copied()).collect();
+    let digits: Vec<u8> = branches.iter().map(|b| b.assumption.digit).collect();
+
+    return Some(Strategy::new(kind, hashmap).with_branches(branches).with_pattern(cells, digits))
+}
+
+
+/// CellForcingChain
+///
+/// One of the probabilities of a cell is its value. Every probability is assumed in
+/// turn and followed with the simple strategies. What follows from all of them is
+/// true, so probabilities that are removed in every outcome are removed from the board.
+///
+/// The index is used as the block of the cell
+pub struct CellForcingChain;
+
+impl SolveProbabilities for CellForcingChain {
+    fn kind() -> StrategyKind {
+        return StrategyKind::CellForcingChain
+    }
+
+    fn orientations() -> Vec<Orientation> {
+        return vec![Orientation::Block]
+    }
+
+    fn logic(board: &Board, orientation: &Orientation, index: u8) -> Vec<Strategy> {
+        return Self::create_subset(board, orientation, index)
+            .missing()
+            .iter()
+            .filter_map(|c| forcing_strategy(
+                board,
+                c.probabilities.iter().map(|d| (c.index, *d)).collect(),
+                Self::kind()
+            ))
+            .collect()
+    }
+}
+
+
+/// DigitForcingChain
+///
+/// A digit that isn't solved in a house is placed in one of the cells in which it is
+/// probable. Every cell is assumed in turn and followed with the simple strategies.
+/// Probabilities that are removed in every outcome are removed from the board.
+///
+/// The index is used as the row, column or block
+pub struct DigitForcingChain;
+
+impl SolveProbabilities for DigitForcingChain {
+    fn kind() -> StrategyKind {
+        return StrategyKind::DigitForcingChain
+    }
+
+    fn orientations() -> Vec<Orientation> {
+        return vec![Orientation::Row, Orientation::Column, Orientation::Block]
+    }
+
+    fn logic(board: &Board, orientation: &Orientation, index: u8) -> Vec<Strategy> {
+        let subset: Subset = Self::create_subset(board, orientation, index);
+
+        return (1..=9)
+            .filter(|d| !subset.contains(d))
+            .filter_map(|d| forcing_strategy(
+                board,
+                subset.missing().iter().filter(|c| c.contains(&d)).map(|c| (c.index, d)).collect(),
+                Self::kind()
+            ))
+            .map(|s| s.with_houses(vec![Self::create_house(orientation, index)], vec![]))
+            .collect()
+    }
+}
+
+
+/// ContradictionForcingNet
+///
+/// A probability is assumed to be the value of its cell and followed with the simple
+/// strategies. When that leads to a contradiction, the probability is removed.
+///
+/// The index is used as the block of the cell
+pub struct ContradictionForcingNet;
+
+impl SolveProbabilities for ContradictionForcingNet {
+    fn kind() -> StrategyKind {
+        return StrategyKind::ContradictionForcingNet
+    }
+
+    fn orientations() -> Vec<Orientation> {
+        return vec![Orientation::Block]
+    }
+
+    fn logic(board: &Board, orientation: &Orientation, index: u8) -> Vec<Strategy> {
+        let mut strategies: Vec<Strategy> = vec![];
+
+        for c in Self::create_subset(board, orientation, index).missing() {
+            for digit in &c.probabilities {
+                let (branch, _) = assume(board, c.index, *digit);
+
+                if !branch.contradiction {
+                    continue
+                }
+
+                strategies.push(
+                    Strategy::new(
+                        Self::kind(),
+                        HashMap::from([(c.index, HashSet::from([*digit]))])
+                    ).with_branches(vec![branch])
+                    .with_pattern(vec![c.index], vec![*digit])
+                );
+            }
+        }
+
+        return strategies
+    }
+}
+
+
+/// KrakenFish
+///
+/// A finned fish of rows and columns, or a franken fish, of which the fins don't all
+/// see a probability in the cover houses. Either the fish is true and the probability
+/// is removed, or one of the fins holds the digit. Every fin is assumed in turn and
+/// followed with the simple strategies. When the probability is removed for every
+/// fin, it is removed from the board.
+///
+/// Every digit is searched on its own
+pub struct KrakenFish;
+
+impl SolveProbabilities for KrakenFish {
+    fn kind() -> StrategyKind {
+        return StrategyKind::KrakenFish
+    }
+
+    fn get_strategies(board: &Board) -> Vec<Strategy> {
+        let mut strategies: Vec<Strategy> = vec![];
+
+        for (base_orientations, cover_orientations) in FRANKEN_ORIENTATIONS {
+            for digit in 1..=9 {
+                let mut assumed: HashMap<u8, (Branch, Board)> = HashMap::new();
+                let mut found: HashSet<Vec<u8>> = HashSet::new();
+
+                for (base, cover, fins) in mixed_fish(board, base_orientations, cover_orientations, digit, 2, false) {
+                    if fins.is_empty() {
+                        continue
+                    }
+
+                    // Probabilities that every fin sees are removed by the finned fish already
+                    let targets: Vec<u8> = fish_targets(board, &base, &cover, digit)
+                        .into_iter()
+                        .filter(|i| !fins.iter().all(|f| board.cells[*f as usize].sees(&board.cells[*i as usize])))
+                        .collect();
+
+                    if targets.is_empty() {
+                        continue
+                    }
+
+                    for fin in &fins {
+                        assumed.entry(*fin).or_insert_with(|| assume(board, *fin, digit));
+                    }
+
+                    let removed: Vec<u8> = targets
+                        .into_iter()
+                        .filter(|i| fins.iter().all(|f| {
+                            let (branch, outcome) = &assumed[f];
+                            branch.contradiction || !outcome.cells[*i as usize].contains(&digit)
+                        }))
+                        .collect();
+
+                    // Only the smallest fish is kept for the same probabilities
+                    if removed.is_empty() || !found.insert(removed.clone()) {
+                        continue
+                    }
+
+                    let hashmap: HashMap<u8, HashSet<u8>> = removed
+                        .into_iter()
+                        .map(|i| (i, HashSet::from([digit])))
+                        .collect();
+
+                    let cells: Vec<u8> = fish_cells(board, &base, digit);
+
+                    strategies.push(
+                        Strategy::new(
+                            Self::kind(),
+                            hashmap
+                        ).with_houses(base, cover)
+                        .with_fins(fins.clone())
+                        .with_branches(fins.iter().map(|f| assumed[f].0.clone()).collect())
+                        .with_pattern(cells, vec![digit])
+                    );
+                }
+            }
+        }
+
+        return strategies
+    }
+}
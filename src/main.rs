@@ -1,51 +1,28 @@
-mod lib;
-mod solvers;
-mod utils;
-
-use std::iter::zip;
-use crate::lib::{Board, Strategy};
-use crate::solvers::*;
-use crate::utils::{brute_force, import_puzzles_from_file};
+use sudoku::utils::import_puzzles_from_file;
+use sudoku::{Board, SolveStatus, Solver};
 
 fn main() {
 
     let puzzles = import_puzzles_from_file();
+    let solver = Solver::default().assume_uniqueness(true);
 
     let mut nr_solved: usize = 0;
     let mut nr_unsolved: usize = 0;
     let mut nr_wrong: usize = 0;
+    let mut nr_unsound: usize = 0;
 
     for puzzle in puzzles {
-        let mut board = Board::from_string(&puzzle[0]);
-
-        let strategy_solvers: [fn(&Board) -> Vec<Strategy>; 5] = [
-            LastRemainingCell::get_strategies,
-            Naked::get_strategies,
-            Hidden::get_strategies,
-            Pointing::get_strategies,
-            BoxLineReduction::get_strategies,
-        ];
-
-        for _ in 0..100 {
-            for s in &strategy_solvers {
-                let strategies: Vec<Strategy> = s(&board);
-                if strategies.is_empty() {
-                    continue
-                }
-
-                for strat in strategies {
-                    board.apply_strategy(strat);
-                }
+        let result = solver.solve_audited(Board::from_string(&puzzle[0]), &puzzle[1]);
 
-                break
-            }
+        if let Some(failure) = &result.failure {
+            nr_unsound += 1;
+            println!("{}: {}", puzzle[0], failure.description());
         }
-
-        if board.to_string().contains("0") {
+        else if result.status != SolveStatus::Solved {
             nr_unsolved += 1;
 
         }
-        else if board.to_string() == puzzle[1] {
+        else if result.board.to_string() == puzzle[1] {
             nr_solved += 1;
         } else {
             nr_wrong += 1;
@@ -55,13 +32,17 @@ fn main() {
     println!("Solved: {}", nr_solved);
     println!("Unsolved: {}", nr_unsolved);
     println!("Wrong: {}", nr_wrong);
+    println!("Unsound: {}", nr_unsound);
 
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison, clippy::needless_borrow, clippy::unnecessary_to_owned)]
 mod tests {
-    // use crate::utils::brute_force;
     use super::*;
+    use sudoku::utils::brute_force;
+    use sudoku::*;
+    use std::collections::{HashMap, HashSet};
 
     #[test]
     fn solve_via_brute_force() {
@@ -77,6 +58,152 @@ mod tests {
         }
     }
 
+    #[test]
+    fn solve_via_solver() {
+        let solver = Solver::default();
+        let puzzles = utils::import_puzzles_from_file();
+
+        for puzzle in puzzles {
+            let result = solver.solve(Board::from_string(&puzzle[0]));
+
+            assert_eq!(result.status, SolveStatus::Solved);
+            assert_eq!(result.board.to_string(), puzzle[1]);
+        }
+    }
+
+    #[test]
+    fn solver_stalls_without_progress() {
+        let board = Board::from_string(
+            &"017903600000080000900000507072010430000402070064370250701000065000030000005601720"
+        );
+
+        let result = Solver::new().solve(board);
+        assert_eq!(result.status, SolveStatus::Stalled);
+
+        let result = Solver::new().with::<Pointing>().solve(result.board);
+        assert_eq!(result.status, SolveStatus::Stalled);
+    }
+
+    #[test]
+    fn solver_detects_contradiction() {
+        let board = Board::from_string(
+            &"123456780000000009000000000000000000000000000000000000000000000000000000000000000"
+        );
+
+        let result = Solver::new().with::<LastRemainingCell>().solve(board);
+        assert_eq!(result.status, SolveStatus::Contradiction);
+        assert_eq!(true, result.board.cells[8].probabilities.is_empty());
+        assert_eq!(result.error, Some(BoardError::EmptyCell(8)));
+    }
+
+    #[test]
+    fn solver_stops_on_duplicate_value() {
+        let board = Board::from_string(
+            &"100000000000000000000000000000000000000000000000000000000000000000000000100000000"
+        );
+
+        let result = Solver::default().solve(board);
+        assert_eq!(result.status, SolveStatus::Contradiction);
+        assert_eq!(true, result.log.steps.is_empty());
+        assert_eq!(result.error, Some(BoardError::DuplicateValue(House::Column(0), 1)));
+    }
+
+    #[test]
+    fn board_check() {
+        let mut board = Board::from_string(
+            &"017903600000080000900000507072010430000402070064370250701000065000030000005601720"
+        );
+        assert_eq!(board.check(), Ok(()));
+
+        // 4 isn't solved in the first row, so it has no place left without the blanks
+        for i in board.row(0).indices_missing() {
+            board.cells[i as usize].remove(4);
+        }
+        assert_eq!(board.check(), Err(BoardError::MissingDigit(House::Row(0), 4)));
+        assert_eq!(board.check().unwrap_err().description(), "4 has no place left in row 1");
+
+        board.cells[3].probabilities.clear();
+        assert_eq!(board.check(), Err(BoardError::EmptyCell(3)));
+        assert_eq!(board.check().unwrap_err().description(), "r1c4 has no probabilities left");
+    }
+
+    #[test]
+    fn solve_log_replays_solve() {
+        let puzzle = "004300209005009001070060043006002087190007400050083000600000105003508690042910300";
+        let result = Solver::default().solve(Board::from_string(puzzle));
+
+        assert_eq!(result.status, SolveStatus::Solved);
+        assert_eq!(false, result.log.steps.is_empty());
+        assert_eq!(true, result.log.replay(puzzle) == result.board);
+
+        // Every step continues from the board the previous step left behind
+        let mut board = Board::from_string(puzzle);
+        for step in &result.log.steps {
+            assert_eq!(true, step.before == board);
+            step.replay(&mut board);
+            assert_eq!(true, step.after == board);
+        }
+    }
+
+    #[test]
+    fn rate_puzzles() {
+        let ratings = rating::rate_puzzles_from_file(true);
+
+        assert_eq!(ratings.len(), utils::import_puzzles_from_file().len());
+        for rating in ratings {
+            assert_eq!(true, rating.solved);
+            assert_eq!(rating.difficulty, Difficulty::from_score(rating.score));
+            assert_eq!(rating.score, rating.hardest.unwrap().kind.weight());
+        }
+    }
+
+    #[test]
+    fn rate_puzzle_hardest_step() {
+        let rating = rate(
+            &"000000000904607000076804100309701080708000301051308702007502610005403208000000000",
+            false
+        );
+
+        // After the hidden singles, only a pointing pair is needed
+        assert_eq!(true, rating.solved);
+        assert_eq!(rating.score, 26);
+        assert_eq!(rating.difficulty, Difficulty::Medium);
+        assert_eq!(rating.hardest.unwrap().kind, StrategyKind::Pointing);
+    }
+
+    /// Creates a solver with the strategies that are simpler than the strategies that
+    /// are tested, harder tests add the strategies they need on top
+    fn basics() -> Solver {
+        Solver::new()
+            .with::<LastRemainingCell>()
+            .with::<Pointing>()
+            .with::<BoxLineReduction>()
+            .with::<Naked>()
+            .with::<Hidden>()
+    }
+
+    /// Resolves the probabilities with the strategies that are simpler than the
+    /// strategies that are tested
+    fn resolve_basics(board: Board) -> Board {
+        basics().solve(board).board
+    }
+
+    /// Creates a solver with the basics, the single digit patterns and the wings, for
+    /// the strategies that come after them
+    fn basics_and_wings() -> Solver {
+        basics()
+            .with::<Fish>()
+            .with::<Skyscraper>()
+            .with::<TwoStringKite>()
+            .with::<FinnedFish>()
+            .with::<EmptyRectangle>()
+            .with::<TurbotFish>()
+            .with::<Coloring>()
+            .with::<XYWing>()
+            .with::<WWing>()
+            .with::<XYZWing>()
+    }
+
     #[test]
     fn resolve_probabilities_pointing_one() {
         let mut board = Board::from_string(
@@ -408,4 +535,1134 @@ mod tests {
         assert_eq!(true, board.cells[50].contains(&4));
         assert_eq!(true, board.cells[50].contains(&9));
     }
+
+
+    #[test]
+    fn resolve_probabilities_fish_x_wing() {
+        let mut board = Board::from_string(
+            &"100000569492056108056109240009640801064010000218035604040500016905061402621000005"
+        );
+
+        board = resolve_basics(board);
+
+        assert_eq!(true, board.cells[1].contains(&7));
+        assert_eq!(true, board.cells[3].contains(&7));
+        assert_eq!(true, board.cells[28].contains(&7));
+        assert_eq!(true, board.cells[39].contains(&7));
+        assert_eq!(true, board.cells[66].contains(&7));
+        assert_eq!(true, board.cells[75].contains(&7));
+        board = Fish::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[1].contains(&7));
+        assert_eq!(false, board.cells[3].contains(&7));
+        assert_eq!(false, board.cells[28].contains(&7));
+        assert_eq!(false, board.cells[39].contains(&7));
+        assert_eq!(false, board.cells[66].contains(&7));
+        assert_eq!(false, board.cells[75].contains(&7));
+    }
+
+    #[test]
+    fn resolve_probabilities_fish_x_wing_columns() {
+        let mut board = Board::from_string(
+            &"002010670700300500000004200500000710070000000040000090000700050089040000100009062"
+        );
+
+        board = resolve_basics(board);
+
+        assert_eq!(true, board.cells[40].contains(&8));
+        assert_eq!(true, board.cells[49].contains(&8));
+        board = Fish::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[40].contains(&8));
+        assert_eq!(false, board.cells[49].contains(&8));
+    }
+
+    #[test]
+    fn resolve_probabilities_fish_swordfish() {
+        let mut board = Board::from_string(
+            &"000280009000137850000090000600025000108700032503000060301600008900000300002000000"
+        );
+
+        board = resolve_basics(board);
+
+        assert_eq!(true, board.cells[6].contains(&4));
+        assert_eq!(true, board.cells[20].contains(&4));
+        assert_eq!(true, board.cells[76].contains(&4));
+        assert_eq!(true, board.cells[78].contains(&4));
+        assert_eq!(true, board.cells[80].contains(&4));
+        board = Fish::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[6].contains(&4));
+        assert_eq!(false, board.cells[20].contains(&4));
+        assert_eq!(false, board.cells[76].contains(&4));
+        assert_eq!(false, board.cells[78].contains(&4));
+        assert_eq!(false, board.cells[80].contains(&4));
+    }
+
+    #[test]
+    fn resolve_probabilities_finned_x_wing() {
+        let mut board = Board::from_string(
+            &"000009063000100750590073040007000800100080004053007000005000006000000500040600020"
+        );
+
+        board = resolve_basics(board);
+        board = Fish::get_and_apply_strategies(board);
+
+        let strategies: Vec<Strategy> = FinnedFish::get_strategies(&board)
+            .into_iter()
+            .filter(|s| s.base.len() == 2 && s.remove.contains_key(&2))
+            .collect();
+        assert_eq!(strategies.len(), 1);
+        assert_eq!(strategies[0].base, vec![House::Row(1), House::Row(8)]);
+        assert_eq!(strategies[0].cover, vec![House::Column(2), House::Column(5)]);
+        assert_eq!(strategies[0].fins, vec![10]);
+
+        assert_eq!(true, board.cells[2].contains(&8));
+        assert_eq!(true, board.cells[10].contains(&8));
+        board = FinnedFish::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[2].contains(&8));
+        assert_eq!(true, board.cells[10].contains(&8));
+    }
+
+    #[test]
+    fn resolve_probabilities_sashimi_x_wing() {
+        let mut board = Board::from_string(
+            &"008000200000002743002006080060074300030621000000030007040007005080000000091500000"
+        );
+
+        board = resolve_basics(board);
+        board = Fish::get_and_apply_strategies(board);
+
+        let strategies: Vec<Strategy> = FinnedFish::get_strategies(&board)
+            .into_iter()
+            .filter(|s| s.remove.contains_key(&7))
+            .collect();
+        assert_eq!(strategies.len(), 1);
+        assert_eq!(strategies[0].base, vec![House::Row(2), House::Row(6)]);
+        assert_eq!(strategies[0].cover, vec![House::Column(4), House::Column(7)]);
+        assert_eq!(strategies[0].fins, vec![24, 26]);
+
+        // Without the fins, row 2 has only a single cell left for the 9
+        assert_eq!(1, board.row(2).missing().iter().filter(|c| c.contains(&9) && ![24, 26].contains(&c.index)).count());
+
+        assert_eq!(true, board.cells[7].contains(&9));
+        board = FinnedFish::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[7].contains(&9));
+    }
+
+
+    #[test]
+    fn resolve_probabilities_xy_wing() {
+        let mut board = Board::from_string(
+            &"004009080000000302010080740000093000007000400000010023070050100058007000026900007"
+        );
+
+        board = resolve_basics(board);
+
+        assert_eq!(true, board.cells[62].contains(&4));
+        assert_eq!(true, board.cells[63].contains(&4));
+        board = XYWing::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[62].contains(&4));
+        assert_eq!(false, board.cells[63].contains(&4));
+    }
+
+    #[test]
+    fn resolve_probabilities_xyz_wing() {
+        let mut board = Board::from_string(
+            &"005000700000680502040000000800702010104000000070006300000060190007000000031204005"
+        );
+
+        board = resolve_basics(board);
+
+        assert_eq!(true, board.cells[68].contains(&3));
+        board = XYZWing::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[68].contains(&3));
+    }
+
+    #[test]
+    fn resolve_probabilities_wxyz_wing() {
+        let mut board = Board::from_string(
+            &"023006000160200000000500000000020001400790002000000503006001020080040709000000040"
+        );
+
+        board = resolve_basics(board);
+
+        assert_eq!(true, board.cells[74].contains(&5));
+        board = WXYZWing::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[74].contains(&5));
+    }
+
+
+    #[test]
+    fn resolve_probabilities_coloring_trap() {
+        let mut board = Board::from_string(
+            &"000302000600900000041007009360000008900800002000005600000000000530096000807200004"
+        );
+
+        board = resolve_basics(board);
+
+        assert_eq!(true, board.cells[48].contains(&4));
+        board = Coloring::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[48].contains(&4));
+    }
+
+    #[test]
+    fn resolve_probabilities_coloring_wrap() {
+        let mut board = Board::from_string(
+            &"003000010700000050000709006000900620389000007061080000004000030010003000000602000"
+        );
+
+        board = resolve_basics(board);
+
+        assert_eq!(true, board.cells[3].contains(&2));
+        assert_eq!(true, board.cells[8].contains(&2));
+        assert_eq!(true, board.cells[10].contains(&2));
+        assert_eq!(true, board.cells[20].contains(&2));
+        assert_eq!(true, board.cells[40].contains(&2));
+        assert_eq!(true, board.cells[55].contains(&2));
+        assert_eq!(true, board.cells[69].contains(&2));
+        board = Coloring::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[3].contains(&2));
+        assert_eq!(false, board.cells[8].contains(&2));
+        assert_eq!(false, board.cells[10].contains(&2));
+        assert_eq!(false, board.cells[20].contains(&2));
+        assert_eq!(false, board.cells[40].contains(&2));
+        assert_eq!(false, board.cells[55].contains(&2));
+        assert_eq!(false, board.cells[69].contains(&2));
+    }
+
+    #[test]
+    fn resolve_probabilities_multi_coloring() {
+        let mut board = Board::from_string(
+            &"036007450000100060000060007080001040070050600103600000000400200018000030460009000"
+        );
+
+        board = resolve_basics(board);
+
+        assert_eq!(true, board.cells[11].contains(&4));
+        board = Coloring::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[11].contains(&4));
+    }
+
+
+    #[test]
+    fn resolve_probabilities_unique_rectangle_type_one() {
+        let mut board = Board::from_string(
+            &"090000080070800003300002905007400000000000060100009000000020100020604070500008040"
+        );
+
+        board = resolve_basics(board);
+
+        assert_eq!(true, board.cells[5].contains(&1));
+        assert_eq!(true, board.cells[5].contains(&5));
+        board = UniqueRectangle::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[5].contains(&1));
+        assert_eq!(false, board.cells[5].contains(&5));
+    }
+
+    #[test]
+    fn resolve_probabilities_unique_rectangle_type_two() {
+        let mut board = Board::from_string(
+            &"005040000100000060000300700007000080080020940090070600000003090001000000074810005"
+        );
+
+        board = resolve_basics(board);
+
+        assert_eq!(true, board.cells[3].contains(&1));
+        assert_eq!(true, board.cells[5].contains(&1));
+        assert_eq!(true, board.cells[8].contains(&1));
+        assert_eq!(true, board.cells[26].contains(&1));
+        board = UniqueRectangle::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[3].contains(&1));
+        assert_eq!(false, board.cells[5].contains(&1));
+        assert_eq!(false, board.cells[8].contains(&1));
+        assert_eq!(false, board.cells[26].contains(&1));
+    }
+
+    #[test]
+    fn resolve_probabilities_unique_rectangle_type_three() {
+        let mut board = Board::from_string(
+            &"007000000000153900000004605000607020000200009089000000500400130806000000000000040"
+        );
+
+        board = resolve_basics(board);
+
+        assert_eq!(true, board.cells[1].contains(&2));
+        assert_eq!(true, board.cells[1].contains(&9));
+        board = UniqueRectangle::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[1].contains(&2));
+        assert_eq!(false, board.cells[1].contains(&9));
+    }
+
+    #[test]
+    fn resolve_probabilities_unique_rectangle_type_four() {
+        let mut board = Board::from_string(
+            &"009000840000940020004300056021600000690050400000030008700010000008203000002006000"
+        );
+
+        board = resolve_basics(board);
+
+        assert_eq!(true, board.cells[10].contains(&7));
+        assert_eq!(true, board.cells[14].contains(&7));
+        board = UniqueRectangle::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[10].contains(&7));
+        assert_eq!(false, board.cells[14].contains(&7));
+    }
+
+    #[test]
+    fn resolve_probabilities_unique_rectangle_type_five() {
+        let mut board = Board::from_string(
+            &"000000305020000400800901000009306700002070008000025000060040090540000100708000000"
+        );
+
+        board = basics_and_wings()
+            .solve(board)
+            .board;
+
+        // Only r3c8 is 6 or 7, the other corners can also be 2
+        assert_eq!(vec![6, 7], board.cells[25].probabilities);
+        assert_eq!(vec![2, 6, 7], board.cells[26].probabilities);
+        assert_eq!(vec![2, 6, 7], board.cells[70].probabilities);
+        assert_eq!(vec![2, 6, 7], board.cells[71].probabilities);
+        assert_eq!(true, board.cells[80].contains(&2));
+        board = UniqueRectangle::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[80].contains(&2));
+    }
+
+    #[test]
+    fn resolve_probabilities_unique_rectangle_type_six() {
+        let mut board = Board::from_string(
+            &"900000000000000870040368001005800014000001090700009600500020000300007000002000706"
+        );
+
+        board = resolve_basics(board);
+
+        assert_eq!(true, board.cells[12].contains(&9));
+        assert_eq!(true, board.cells[76].contains(&9));
+        board = UniqueRectangle::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[12].contains(&9));
+        assert_eq!(false, board.cells[76].contains(&9));
+    }
+
+    #[test]
+    fn resolve_probabilities_hidden_unique_rectangle() {
+        let mut board = Board::from_string(
+            &"005000700000680502040000000800702010104000000070006300000060190007000000031204005"
+        );
+
+        board = resolve_basics(board);
+
+        assert_eq!(true, board.cells[22].contains(&3));
+        board = HiddenUniqueRectangle::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[22].contains(&3));
+    }
+
+    #[test]
+    fn solver_uses_uniqueness_only_when_assumed() {
+        let board = resolve_basics(Board::from_string(
+            &"090000080070800003300002905007400000000000060100009000000020100020604070500008040"
+        ));
+        let solver = Solver::new().with::<UniqueRectangle>();
+
+        let result = solver.solve(board.clone());
+        assert_eq!(result.status, SolveStatus::Stalled);
+        assert_eq!(true, result.board == board);
+
+        let result = solver.assume_uniqueness(true).solve(board);
+        assert_eq!(false, result.board.cells[5].contains(&1));
+        assert_eq!(false, result.board.cells[5].contains(&5));
+    }
+
+
+    #[test]
+    fn resolve_probabilities_bivalue_universal_grave() {
+        let mut board = Board::from_string(
+            &"000350780700040900001078000060007000008000090005000308000500012000002670003000000"
+        );
+
+        board = resolve_basics(board);
+
+        assert_eq!(1, board.cells.iter().filter(|c| c.probabilities.len() > 2).count());
+        assert_eq!(3, board.cells[31].probabilities.len());
+        assert_eq!(true, board.cells[31].contains(&3));
+        assert_eq!(true, board.cells[31].contains(&8));
+        board = BivalueUniversalGrave::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[31].contains(&3));
+        assert_eq!(false, board.cells[31].contains(&8));
+        assert_eq!(true, board.cells[31].solved());
+    }
+
+    #[test]
+    fn resolve_probabilities_bivalue_universal_grave_not_found() {
+        let mut board = Board::from_string(
+            &"000350780700040900001078000060007000008000090005000308000500012000002670003000000"
+        );
+
+        board = LastRemainingCell::get_and_apply_strategies(board);
+
+        assert_eq!(true, BivalueUniversalGrave::get_strategies(&board).is_empty());
+    }
+
+    #[test]
+    fn resolve_probabilities_bivalue_universal_grave_broken_house() {
+        let mut board = Board::from_string(
+            &"050072060430190000082050109510700236270610080060205907095864700040921358028537090"
+        );
+
+        board = LastRemainingCell::get_and_apply_strategies(board);
+
+        // Only r5c3 has three probabilities, but 8 is only probable in r1c7 of row 1
+        assert_eq!(1, board.cells.iter().filter(|c| c.probabilities.len() > 2).count());
+        assert_eq!(3, board.cells[38].probabilities.len());
+        assert_eq!(1, board.row(0).missing().iter().filter(|c| c.contains(&8)).count());
+        assert_eq!(true, BivalueUniversalGrave::get_strategies(&board).is_empty());
+    }
+
+
+    #[test]
+    fn resolve_probabilities_alternating_inference_chain() {
+        let mut board = Board::from_string(
+            &"036007450000100060000060007080001040070050600103600000000400200018000030460009000"
+        );
+
+        board = resolve_basics(board);
+
+        assert_eq!(true, board.cells[11].contains(&4));
+        assert_eq!(true, board.cells[19].contains(&5));
+        assert_eq!(true, board.cells[20].contains(&5));
+        assert_eq!(true, board.cells[21].contains(&2));
+        assert_eq!(true, board.cells[23].contains(&2));
+        board = AlternatingInferenceChain::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[11].contains(&4));
+        assert_eq!(false, board.cells[19].contains(&5));
+        assert_eq!(false, board.cells[20].contains(&5));
+        assert_eq!(false, board.cells[21].contains(&2));
+        assert_eq!(false, board.cells[23].contains(&2));
+    }
+
+    #[test]
+    fn resolve_probabilities_alternating_inference_chain_loop() {
+        let mut board = Board::from_string(
+            &"023006000160200000000500000000020001400790002000000503006001020080040709000000040"
+        );
+
+        board = resolve_basics(board);
+
+        let strategies: Vec<Strategy> = AlternatingInferenceChain::get_strategies(&board);
+        let continuous: Vec<&Strategy> = strategies
+            .iter()
+            .filter(|s| !s.chain.last().unwrap().strong)
+            .collect();
+        assert_eq!(false, continuous.is_empty());
+        assert_eq!(true, continuous.iter().all(|s| s.chain.first().unwrap().from == s.chain.last().unwrap().to));
+
+        assert_eq!(true, board.cells[54].contains(&5));
+        assert_eq!(true, board.cells[70].contains(&3));
+        assert_eq!(true, board.cells[70].contains(&6));
+        board = AlternatingInferenceChain::apply_strategies(board, strategies);
+        assert_eq!(false, board.cells[54].contains(&5));
+        assert_eq!(false, board.cells[70].contains(&3));
+        assert_eq!(false, board.cells[70].contains(&6));
+    }
+
+    #[test]
+    fn alternating_inference_chain_links() {
+        let board = resolve_basics(Board::from_string(
+            &"036007450000100060000060007080001040070050600103600000000400200018000030460009000"
+        ));
+
+        let strategies: Vec<Strategy> = AlternatingInferenceChain::get_strategies(&board);
+        assert_eq!(false, strategies.is_empty());
+
+        for strategy in &strategies {
+            assert_eq!(true, strategy.chain.first().unwrap().strong);
+            for (n, link) in strategy.chain.iter().enumerate() {
+                assert_eq!(n % 2 == 0, link.strong);
+            }
+            for (a, b) in strategy.chain.iter().zip(strategy.chain.iter().skip(1)) {
+                assert_eq!(a.to, b.from);
+            }
+        }
+
+        let grouped = strategies
+            .iter()
+            .any(|s| s.chain.iter().any(|l| l.from.cells.len() > 1));
+        assert_eq!(true, grouped);
+    }
+
+
+    #[test]
+    fn resolve_probabilities_x_cycles_continuous() {
+        let mut board = Board::from_string(
+            &"050260000200000109001004008000620090760100040000000000903080000005000030000002800"
+        );
+
+        board = resolve_basics(board);
+
+        assert_eq!(true, board.cells[72].contains(&6));
+        assert_eq!(true, board.cells[80].contains(&6));
+        board = XCycles::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[72].contains(&6));
+        assert_eq!(false, board.cells[80].contains(&6));
+    }
+
+    #[test]
+    fn resolve_probabilities_x_cycles_discontinuous() {
+        let mut board = Board::from_string(
+            &"800010900000080205040000000050140060002009040000760000000006370000000002309200001"
+        );
+
+        board = resolve_basics(board);
+
+        assert_eq!(false, board.cells[39].solved());
+        assert_eq!(true, board.cells[37].contains(&8));
+        assert_eq!(true, board.cells[50].contains(&8));
+        assert_eq!(true, board.cells[66].contains(&8));
+        board = XCycles::get_and_apply_strategies(board);
+        assert_eq!(8, board.get(39));
+        assert_eq!(false, board.cells[37].contains(&8));
+        assert_eq!(false, board.cells[50].contains(&8));
+        assert_eq!(false, board.cells[66].contains(&8));
+    }
+
+
+    #[test]
+    fn resolve_probabilities_als_xz() {
+        let mut board = Board::from_string(
+            &"036007450000100060000060007080001040070050600103600000000400200018000030460009000"
+        );
+
+        board = resolve_basics(board);
+
+        assert_eq!(true, board.cells[74].contains(&5));
+        board = AlsXZ::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[74].contains(&5));
+    }
+
+    #[test]
+    fn resolve_probabilities_als_xy_wing() {
+        let mut board = Board::from_string(
+            &"000040007008003001500700300000080002907010000003006900040067000609800000705020006"
+        );
+
+        board = basics()
+            .with::<AlsXZ>()
+            .solve(board)
+            .board;
+
+        assert_eq!(true, board.cells[5].contains(&1));
+        assert_eq!(true, board.cells[42].contains(&5));
+        board = AlsXYWing::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[5].contains(&1));
+        assert_eq!(false, board.cells[42].contains(&5));
+    }
+
+    #[test]
+    fn resolve_probabilities_als_chain() {
+        let mut board = Board::from_string(
+            &"000006300020000007001900800500020000008030090000047200010500000870004000004000006"
+        );
+
+        board = basics()
+            .with::<AlsXZ>()
+            .with::<AlsXYWing>()
+            .solve(board)
+            .board;
+
+        assert_eq!(true, AlsChain::get_strategies(&board).iter().all(|s| s.base.len() >= 3));
+        assert_eq!(true, board.cells[53].contains(&3));
+        board = AlsChain::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[53].contains(&3));
+    }
+
+
+    #[test]
+    fn resolve_probabilities_sue_de_coq() {
+        let mut board = Board::from_string(
+            &"800006004004700000090003800630900700000000920009010005000009030000000248000401006"
+        );
+
+        board = resolve_basics(board);
+
+        assert_eq!(true, board.cells[2].contains(&1));
+        assert_eq!(true, board.cells[2].contains(&5));
+        assert_eq!(true, board.cells[37].contains(&7));
+        assert_eq!(true, board.cells[55].contains(&2));
+        board = SueDeCoq::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[2].contains(&1));
+        assert_eq!(false, board.cells[2].contains(&5));
+        assert_eq!(false, board.cells[37].contains(&7));
+        assert_eq!(false, board.cells[55].contains(&2));
+    }
+
+
+    #[test]
+    fn resolve_probabilities_cell_forcing_chain() {
+        let mut board = Board::from_string(
+            &"036007450000100060000060007080001040070050600103600000000400200018000030460009000"
+        );
+
+        board = resolve_basics(board);
+
+        let strategies: Vec<Strategy> = CellForcingChain::get_strategies(&board);
+        for strategy in &strategies {
+            let cell: &Vec<u8> = &strategy.branches[0].assumption.cells;
+            assert_eq!(board.cells[cell[0] as usize].probabilities.len(), strategy.branches.len());
+            assert_eq!(true, strategy.branches.iter().all(|b| &b.assumption.cells == cell));
+        }
+
+        assert_eq!(true, board.cells[9].contains(&2));
+        assert_eq!(true, board.cells[11].contains(&4));
+        board = CellForcingChain::apply_strategies(board, strategies);
+        assert_eq!(false, board.cells[9].contains(&2));
+        assert_eq!(false, board.cells[11].contains(&4));
+    }
+
+    #[test]
+    fn resolve_probabilities_digit_forcing_chain() {
+        let mut board = Board::from_string(
+            &"036007450000100060000060007080001040070050600103600000000400200018000030460009000"
+        );
+
+        board = resolve_basics(board);
+
+        assert_eq!(true, board.cells[0].contains(&2));
+        board = DigitForcingChain::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[0].contains(&2));
+    }
+
+    #[test]
+    fn resolve_probabilities_contradiction_forcing_net() {
+        let mut board = Board::from_string(
+            &"036007450000100060000060007080001040070050600103600000000400200018000030460009000"
+        );
+
+        board = resolve_basics(board);
+
+        let strategies: Vec<Strategy> = ContradictionForcingNet::get_strategies(&board);
+        assert_eq!(true, strategies.iter().all(|s| s.branches.len() == 1 && s.branches[0].contradiction));
+
+        assert_eq!(true, board.cells[9].contains(&2));
+        board = ContradictionForcingNet::apply_strategies(board, strategies);
+        assert_eq!(false, board.cells[9].contains(&2));
+    }
+
+
+    #[test]
+    fn resolve_probabilities_skyscraper() {
+        let mut board = Board::from_string(
+            &"000310402720000000090600000000150070800000000065002000002000007300000610016005000"
+        );
+
+        board = resolve_basics(board);
+
+        assert_eq!(true, board.cells[11].contains(&1));
+        assert_eq!(true, board.cells[26].contains(&1));
+        board = Skyscraper::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[11].contains(&1));
+        assert_eq!(false, board.cells[26].contains(&1));
+    }
+
+    #[test]
+    fn resolve_probabilities_two_string_kite() {
+        let mut board = Board::from_string(
+            &"036007450000100060000060007080001040070050600103600000000400200018000030460009000"
+        );
+
+        board = resolve_basics(board);
+
+        assert_eq!(true, board.cells[11].contains(&4));
+        board = TwoStringKite::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[11].contains(&4));
+    }
+
+    #[test]
+    fn resolve_probabilities_empty_rectangle() {
+        let mut board = Board::from_string(
+            &"002000600001040025840000000010500076005801000007000000900400087000000500508709400"
+        );
+
+        board = resolve_basics(board);
+
+        assert_eq!(true, board.cells[60].contains(&3));
+        board = EmptyRectangle::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[60].contains(&3));
+    }
+
+    #[test]
+    fn resolve_probabilities_turbot_fish() {
+        let mut board = Board::from_string(
+            &"000009063000100750590073040007000800100080004053007000005000006000000500040600020"
+        );
+
+        board = resolve_basics(board);
+
+        assert_eq!(true, board.cells[2].contains(&8));
+        assert_eq!(true, Skyscraper::get_strategies(&board).iter().all(|s| !s.remove.contains_key(&2)));
+        assert_eq!(true, TwoStringKite::get_strategies(&board).iter().all(|s| !s.remove.contains_key(&2)));
+        board = TurbotFish::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[2].contains(&8));
+    }
+
+    #[test]
+    fn resolve_probabilities_turbot_fish_skips_skyscraper() {
+        let mut board = Board::from_string(
+            &"000310402720000000090600000000150070800000000065002000002000007300000610016005000"
+        );
+
+        board = resolve_basics(board);
+
+        let skyscrapers: Vec<Strategy> = Skyscraper::get_strategies(&board);
+        assert_eq!(false, skyscrapers.is_empty());
+        assert_eq!(true, TurbotFish::get_strategies(&board)
+            .iter()
+            .all(|t| skyscrapers.iter().all(|s| s.base != t.base)));
+    }
+
+
+    #[test]
+    fn resolve_probabilities_w_wing() {
+        let mut board = Board::from_string(
+            &"008000200000002743002006080060074300030621000000030007040007005080000000091500000"
+        );
+
+        board = resolve_basics(board);
+
+        assert_eq!(true, board.cells[27].contains(&9));
+        assert_eq!(true, board.cells[29].contains(&9));
+        board = WWing::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[27].contains(&9));
+        assert_eq!(false, board.cells[29].contains(&9));
+    }
+
+    #[test]
+    fn resolve_probabilities_w_wing_second_example() {
+        let mut board = Board::from_string(
+            &"800006004004700000090003800630900700000000920009010005000009030000000248000401006"
+        );
+
+        board = resolve_basics(board);
+
+        assert_eq!(true, board.cells[1].contains(&5));
+        assert_eq!(true, board.cells[2].contains(&5));
+        board = WWing::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[1].contains(&5));
+        assert_eq!(false, board.cells[2].contains(&5));
+    }
+
+
+    #[test]
+    fn resolve_probabilities_medusa_two_colors_in_cell() {
+        let mut board = Board::from_string(
+            &"000046100200050609000000080000000700907000005001203008000000051400900002059010000"
+        );
+
+        board = resolve_basics(board);
+
+        assert_eq!(true, board.cells[0].contains(&3));
+        board = Medusa::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[0].contains(&3));
+    }
+
+    #[test]
+    fn resolve_probabilities_medusa_color_twice_in_house() {
+        let mut board = Board::from_string(
+            &"000031008100200000070090005200000003000000000030700600052008000060109070010006040"
+        );
+
+        board = resolve_basics(board);
+
+        assert_eq!(true, board.cells[0].contains(&6));
+        assert_eq!(true, board.cells[63].contains(&4));
+        board = Medusa::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[0].contains(&6));
+        assert_eq!(false, board.cells[63].contains(&4));
+    }
+
+    #[test]
+    fn medusa_rules() {
+        let examples = [
+            ("000280000095700001200000700040100008903000000500070200180900000000004006000000054", MedusaRule::ColorTwiceInCell),
+            ("000031008100200000070090005200000003000000000030700600052008000060109070010006040", MedusaRule::ColorTwiceInHouse),
+            ("000046100200050609000000080000000700907000005001203008000000051400900002059010000", MedusaRule::TwoColorsInCell),
+            ("000000000800000030504830020000301007020450900905000000400000000006020503000047010", MedusaRule::TwoColorsElsewhere),
+            ("000000002000080000085041307901006000050000003030000709000003000306950000400800200", MedusaRule::TwoColorsUnitAndCell),
+            ("010800039003100800704000100201036004000080005006009000040020000007900300900000028", MedusaRule::CellEmptiedByColor),
+        ];
+
+        for (puzzle, rule) in examples {
+            let board = resolve_basics(Board::from_string(puzzle));
+            let strategies = Medusa::get_strategies(&board);
+
+            assert_eq!(true, strategies.iter().any(|s| s.rule == Some(rule)));
+        }
+    }
+
+
+    #[test]
+    fn resolve_probabilities_death_blossom() {
+        let mut board = Board::from_string(
+            &"008000200000002743002006080060074300030621000000030007040007005080000000091500000"
+        );
+
+        board = resolve_basics(board);
+
+        assert_eq!(true, board.cells[3].contains(&4));
+        assert_eq!(true, board.cells[52].contains(&9));
+        board = DeathBlossom::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[3].contains(&4));
+        assert_eq!(false, board.cells[52].contains(&9));
+    }
+
+    #[test]
+    fn resolve_probabilities_death_blossom_after_als_chain() {
+        let mut board = Board::from_string(
+            &"000006300020000007001900800500020000008030090000047200010500000870004000004000006"
+        );
+
+        board = basics()
+            .with::<AlsXZ>()
+            .with::<AlsXYWing>()
+            .with::<AlsChain>()
+            .solve(board)
+            .board;
+
+        assert_eq!(true, board.cells[62].contains(&8));
+        board = DeathBlossom::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[62].contains(&8));
+    }
+
+
+    #[test]
+    fn resolve_probabilities_aligned_pair_exclusion() {
+        let mut board = Board::from_string(
+            &"036007450000100060000060007080001040070050600103600000000400200018000030460009000"
+        );
+
+        board = resolve_basics(board);
+
+        assert_eq!(true, board.cells[74].contains(&5));
+        board = AlignedPairExclusion::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[74].contains(&5));
+    }
+
+    #[test]
+    fn resolve_probabilities_aligned_pair_exclusion_across_houses() {
+        let mut board = Board::from_string(
+            &"000006300020000007001900800500020000008030090000047200010500000870004000004000006"
+        );
+
+        board = resolve_basics(board);
+
+        // r3c2 and r6c3 share no house, the sets that exclude a combination are in
+        // their common peers
+        let strategy: Strategy = AlignedPairExclusion::get_strategies(&board)
+            .into_iter()
+            .find(|s| s.cells == vec![19, 47])
+            .unwrap();
+
+        assert_eq!(true, strategy.base.is_empty());
+        assert_eq!(true, board.cells[47].contains(&9));
+        board = AlignedPairExclusion::apply_strategies(board, vec![strategy]);
+        assert_eq!(false, board.cells[47].contains(&9));
+    }
+
+    #[test]
+    fn resolve_probabilities_aligned_triple_exclusion() {
+        let mut board = Board::from_string(
+            &"000040007008003001500700300000080002907010000003006900040067000609800000705020006"
+        );
+
+        board = basics_and_wings()
+            .with::<Medusa>()
+            .with::<AlignedPairExclusion>()
+            .with::<AlsXZ>()
+            .with::<AlsXYWing>()
+            .solve(board)
+            .board;
+
+        assert_eq!(true, board.cells[2].contains(&2));
+        board = AlignedTripleExclusion::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[2].contains(&2));
+    }
+
+
+    #[test]
+    fn resolve_probabilities_franken_fish() {
+        let mut board = Board::from_string(
+            &"090000080070800003300002905007400000000000060100009000000020100020604070500008040"
+        );
+
+        board = basics_and_wings()
+            .with::<XCycles>()
+            .solve(board)
+            .board;
+
+        assert_eq!(true, board.cells[4].contains(&6));
+        assert_eq!(true, board.cells[28].contains(&6));
+        assert_eq!(true, board.cells[31].contains(&6));
+        board = FrankenFish::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[4].contains(&6));
+        assert_eq!(false, board.cells[28].contains(&6));
+        assert_eq!(false, board.cells[31].contains(&6));
+    }
+
+    #[test]
+    fn resolve_probabilities_mutant_fish() {
+        let mut board = Board::from_string(
+            &"050001030006000007001000000060700029080002004500030000630500010000000070400690000"
+        );
+
+        board = basics_and_wings()
+            .with::<XCycles>()
+            .with::<FrankenFish>()
+            .with::<Medusa>()
+            .solve(board)
+            .board;
+
+        assert_eq!(true, board.cells[58].contains(&8));
+        assert_eq!(true, board.cells[59].contains(&8));
+        board = MutantFish::get_and_apply_strategies(board);
+        assert_eq!(false, board.cells[58].contains(&8));
+        assert_eq!(false, board.cells[59].contains(&8));
+    }
+
+
+    #[test]
+    fn resolve_probabilities_kraken_fish() {
+        let mut board = Board::from_string(
+            &"036007450000100060000060007080001040070050600103600000000400200018000030460009000"
+        );
+
+        board = resolve_basics(board);
+
+        let strategies: Vec<Strategy> = KrakenFish::get_strategies(&board);
+        assert_eq!(true, strategies.iter().all(|s| !s.fins.is_empty() && s.branches.len() == s.fins.len()));
+
+        assert_eq!(true, board.cells[17].contains(&2));
+        board = KrakenFish::apply_strategies(board, strategies);
+        assert_eq!(false, board.cells[17].contains(&2));
+    }
+
+
+    #[test]
+    fn resolve_probabilities_naked_single() {
+        let mut board = Board::from_string(
+            &"023450000000000000000000000600000000700000000800000000900000000000000000000000000"
+        );
+
+        let strategies: Vec<Strategy> = NakedSingle::get_strategies(&board);
+        assert_eq!(1, strategies.len());
+        assert_eq!(Some(&1), strategies[0].place.get(&0));
+
+        let step = Step::record(&mut board, strategies.into_iter().next().unwrap()).unwrap();
+        assert_eq!(vec![1], board.cells[0].probabilities);
+        assert_eq!("Naked Single r1c1 = 1", step.description());
+    }
+
+    #[test]
+    fn resolve_probabilities_hidden_single() {
+        let mut board = Board::from_string(
+            &"000000000100000000000010000000000000000000100000000000000000000000000010000000000"
+        );
+
+        board = LastRemainingCell::get_and_apply_strategies(board);
+
+        let strategies: Vec<Strategy> = HiddenSingle::get_strategies(&board);
+        let strategy: Strategy = strategies
+            .into_iter()
+            .find(|s| s.base == vec![House::Row(0)])
+            .unwrap();
+        assert_eq!(true, strategy.remove.is_empty());
+        assert_eq!(Some(&1), strategy.place.get(&8));
+
+        let step = Step::record(&mut board, strategy).unwrap();
+        assert_eq!(vec![1], board.cells[8].probabilities);
+        assert_eq!("Hidden Single r1c9 = 1", step.description());
+    }
+
+    #[test]
+    fn solve_log_describes_placements() {
+        let result = Solver::default().solve(Board::from_string(
+            &"017903600000080000900000507072010430000402070064370250701000065000030000005601720"
+        ));
+
+        let descriptions: Vec<String> = result.log.steps.iter().map(|s| s.description()).collect();
+        assert_eq!(true, descriptions.contains(&String::from("Hidden Single r8c1 = 6")));
+        assert_eq!(true, descriptions.contains(&String::from("Naked Single r2c4 = 1")));
+        assert_eq!(true, descriptions.contains(&String::from("Pointing r5c5 <> 9, r5c7 <> 9, r5c9 <> 9")));
+    }
+
+
+    #[test]
+    fn strategy_explanation_pointing() {
+        let board = Solver::new()
+            .with::<LastRemainingCell>()
+            .with::<HiddenSingle>()
+            .solve(Board::from_string(
+                &"017903600000080000900000507072010430000402070064370250701000065000030000005601720"
+            ))
+            .board;
+
+        let strategy: Strategy = Pointing::get_strategies(&board)
+            .into_iter()
+            .find(|s| s.base == vec![House::Block(3)] && s.digits == vec![9])
+            .unwrap();
+
+        assert_eq!(strategy.cover, vec![House::Row(4)]);
+        assert_eq!(strategy.cells, vec![37, 38]);
+        assert_eq!(
+            strategy.explanation(),
+            "Pointing: 9 in block 4 is locked to row 5, remove 9 from r5c5, r5c7, r5c9"
+        );
+    }
+
+    #[test]
+    fn strategies_have_evidence() {
+        let board = resolve_basics(Board::from_string(
+            &"036007450000100060000060007080001040070050600103600000000400200018000030460009000"
+        ));
+
+        let solvers: Vec<fn(&Board) -> Vec<Strategy>> = vec![
+            NakedSingle::get_strategies, LastRemainingCell::get_strategies, HiddenSingle::get_strategies,
+            Pointing::get_strategies, BoxLineReduction::get_strategies, Naked::get_strategies,
+            Hidden::get_strategies, Fish::get_strategies, Skyscraper::get_strategies,
+            TwoStringKite::get_strategies, FinnedFish::get_strategies, EmptyRectangle::get_strategies,
+            TurbotFish::get_strategies, Coloring::get_strategies, XYWing::get_strategies,
+            WWing::get_strategies, XYZWing::get_strategies, UniqueRectangle::get_strategies,
+            WXYZWing::get_strategies, HiddenUniqueRectangle::get_strategies, XCycles::get_strategies,
+            FrankenFish::get_strategies, SueDeCoq::get_strategies, Medusa::get_strategies,
+            MutantFish::get_strategies, AlignedPairExclusion::get_strategies,
+            BivalueUniversalGrave::get_strategies, AlsXZ::get_strategies, AlsXYWing::get_strategies,
+            AlignedTripleExclusion::get_strategies, AlsChain::get_strategies, DeathBlossom::get_strategies,
+            AlternatingInferenceChain::get_strategies, KrakenFish::get_strategies,
+            CellForcingChain::get_strategies, DigitForcingChain::get_strategies,
+            ContradictionForcingNet::get_strategies,
+        ];
+
+        // Every strategy that changes the board names its digits and its pattern
+        for get_strategies in solvers {
+            for strategy in get_strategies(&board) {
+                if strategy.remove.values().all(|p| p.is_empty()) && strategy.place.is_empty() {
+                    continue
+                }
+
+                assert_eq!(false, strategy.digits.is_empty(), "{}", strategy.explanation());
+                assert_eq!(false, strategy.cells.is_empty(), "{}", strategy.explanation());
+            }
+        }
+    }
+
+
+    #[test]
+    fn strategy_kinds_are_distinct() {
+        let kinds: Vec<StrategyKind> = Solver::default().kinds();
+
+        // Every solver of the default pipeline reports its own kind
+        assert_eq!(kinds, StrategyKind::all());
+        assert_eq!(kinds.len(), kinds.iter().collect::<HashSet<&StrategyKind>>().len());
+        assert_eq!(kinds.len(), kinds.iter().map(|k| k.id()).collect::<HashSet<&str>>().len());
+        assert_eq!(kinds.len(), kinds.iter().map(|k| k.display_name()).collect::<HashSet<&str>>().len());
+    }
+
+
+    /// Removes every probability of the first unsolved cell of the first row, which
+    /// always removes the digit of the solution
+    struct EmptyFirstCell;
+
+    impl SolveProbabilities for EmptyFirstCell {
+        fn kind() -> StrategyKind {
+            StrategyKind::Naked
+        }
+
+        fn orientations() -> Vec<Orientation> {
+            vec![Orientation::Row]
+        }
+
+        fn logic(board: &Board, orientation: &Orientation, index: u8) -> Vec<Strategy> {
+            let cell = &Self::create_subset(board, orientation, index).missing()[0];
+            vec![
+                Strategy::new(Self::kind(), HashMap::from([(cell.index, cell.as_set())]))
+                    .with_houses(vec![Self::create_house(orientation, index)], vec![])
+            ]
+        }
+    }
+
+    /// Places the last probability of the first unsolved cell of the first row, which
+    /// is wrong for the first puzzle of the file
+    struct PlaceFirstCell;
+
+    impl SolveProbabilities for PlaceFirstCell {
+        fn kind() -> StrategyKind {
+            StrategyKind::NakedSingle
+        }
+
+        fn orientations() -> Vec<Orientation> {
+            vec![Orientation::Row]
+        }
+
+        fn logic(board: &Board, orientation: &Orientation, index: u8) -> Vec<Strategy> {
+            let cell = &Self::create_subset(board, orientation, index).missing()[0];
+            vec![
+                Strategy::new(Self::kind(), HashMap::new())
+                    .with_place(HashMap::from([(cell.index, *cell.probabilities.last().unwrap())]))
+            ]
+        }
+    }
+
+    #[test]
+    fn solve_audited_puzzles() {
+        let solver = Solver::default().assume_uniqueness(true);
+
+        for puzzle in utils::import_puzzles_from_file().iter().take(20) {
+            let result = solver.solve_audited(Board::from_string(&puzzle[0]), &puzzle[1]);
+            assert_eq!(true, result.failure.is_none());
+            assert_eq!(result.status, SolveStatus::Solved);
+            assert_eq!(result.board.to_string(), puzzle[1]);
+        }
+    }
+
+    #[test]
+    fn solve_audited_reports_unsound_strategy() {
+        let puzzle = &utils::import_puzzles_from_file()[0];
+        let board = Board::from_string(&puzzle[0]);
+        let cell: u8 = board.row(0).missing()[0].index;
+
+        let result = Solver::new()
+            .with::<EmptyFirstCell>()
+            .solve_audited(board, &puzzle[1]);
+
+        assert_eq!(result.status, SolveStatus::Unsound);
+        assert_eq!(true, result.log.steps.is_empty());
+
+        let failure = result.failure.unwrap();
+        assert_eq!(failure.kind, StrategyKind::Naked);
+        assert_eq!(failure.cell, cell);
+        assert_eq!(failure.digit, Board::from_string(&puzzle[1]).get(cell));
+        assert_eq!(failure.houses, vec![House::Row(0)]);
+        assert_eq!(failure.placed, None);
+        assert_eq!(true, failure.description().starts_with(&format!("Naked removed {} from {}", failure.digit, cell_name(cell))));
+    }
+
+    #[test]
+    fn solve_audited_reports_wrong_placement() {
+        let puzzle = &import_puzzles_from_file()[0];
+        let board = Board::from_string(&puzzle[0]);
+        let cell: Cell = board.row(0).missing()[0].clone();
+        let placed: u8 = *cell.probabilities.last().unwrap();
+        let digit: u8 = Board::from_string(&puzzle[1]).get(cell.index);
+        assert_ne!(placed, digit);
+
+        let result = Solver::new()
+            .with::<PlaceFirstCell>()
+            .solve_audited(board, &puzzle[1]);
+
+        assert_eq!(result.status, SolveStatus::Unsound);
+
+        let failure = result.failure.unwrap();
+        assert_eq!(failure.kind, StrategyKind::NakedSingle);
+        assert_eq!(failure.cell, cell.index);
+        assert_eq!(failure.digit, digit);
+        assert_eq!(failure.placed, Some(placed));
+        assert_eq!(true, failure.description().starts_with(
+            &format!("Naked Single placed {} in {} instead of {}", placed, cell_name(cell.index), digit)
+        ));
+    }
 }
\ No newline at end of file
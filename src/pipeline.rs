@@ -0,0 +1,305 @@
+use crate::als::*;
+use crate::chains::*;
+use crate::forcing::*;
+use crate::board::*;
+use crate::solvers::*;
+use crate::trace::*;
+
+
+/// The way a run of the Solver ended
+#[derive(Debug, PartialEq)]
+pub enum SolveStatus {
+    /// Every cell has a single probability left
+    Solved,
+    /// None of the strategies could make progress anymore
+    Stalled,
+    /// The board is inconsistent and can't be solved, the error tells why
+    Contradiction,
+    /// A strategy removed a digit of the solution or placed a wrong one, only found
+    /// in audit mode
+    Unsound,
+}
+
+
+/// ### AuditFailure
+///
+/// A strategy that removed the digit of the known solution from a cell, or that
+/// placed another digit in it
+///
+/// ### Attributes
+///    kind (StrategyKind): The technique of the strategy
+///    cell (u8): The index of the cell that lost its solution
+///    digit (u8): The digit of the solution
+///    placed (Option<u8>): The digit that was placed instead, None when the solution was removed
+///    houses (Vec<House>): The base and cover houses of the strategy
+///    explanation (String): The explanation of the strategy
+#[derive(Debug)]
+pub struct AuditFailure {
+    pub kind: StrategyKind,
+    pub cell: u8,
+    pub digit: u8,
+    pub placed: Option<u8>,
+    pub houses: Vec<House>,
+    pub explanation: String,
+}
+
+impl AuditFailure {
+    /// Checks the strategy against the solution before it is applied
+    ///
+    /// ### Arguments
+    ///   board (&Board): the sudoku before the strategy is applied
+    ///   strategy (&Strategy): the strategy
+    ///   solution (&Board): the solved sudoku
+    ///
+    /// ### Returns
+    ///   Option<AuditFailure>: the first cell of which the solution is removed or replaced, if any
+    fn check(board: &Board, strategy: &Strategy, solution: &Board) -> Option<AuditFailure> {
+        let cell: u8 = board.cells
+            .iter()
+            .filter(|c| c.contains(&solution.get(c.index)))
+            .map(|c| c.index)
+            .find(|i| {
+                let digit: u8 = solution.get(*i);
+                strategy.remove.get(i).is_some_and(|p| p.contains(&digit))
+                    || strategy.place.get(i).is_some_and(|value| *value != digit)
+            })?;
+
+        let digit: u8 = solution.get(cell);
+        let removed: bool = strategy.remove.get(&cell).is_some_and(|p| p.contains(&digit));
+
+        return Some(AuditFailure {
+            kind: strategy.kind,
+            cell,
+            digit,
+            placed: if removed { None } else { strategy.place.get(&cell).copied() },
+            houses: strategy.base.iter().chain(strategy.cover.iter()).copied().collect(),
+            explanation: strategy.explanation(),
+        })
+    }
+
+    /// Describes the failure, for example
+    /// "Pointing removed 3 from r2c4 in block 1, row 2 (Pointing: 3 in block 1 ...)" or
+    /// "Hidden Single placed 5 in r1c1 instead of 3 in row 1 (Hidden Single: ...)"
+    ///
+    /// ### Returns
+    ///   String: the description
+    pub fn description(&self) -> String {
+        let houses: String = if self.houses.is_empty() {
+            String::new()
+        } else {
+            format!(" in {}", self.houses.iter().map(|h| h.name()).collect::<Vec<String>>().join(", "))
+        };
+
+        let action: String = match self.placed {
+            Some(value) => format!("placed {} in {} instead of {}", value, cell_name(self.cell), self.digit),
+            None => format!("removed {} from {}", self.digit, cell_name(self.cell)),
+        };
+
+        return format!("{} {}{} ({})", self.kind.display_name(), action, houses, self.explanation)
+    }
+}
+
+
+/// ### SolveResult
+///
+/// Contains the board as the Solver left it, the reason the Solver stopped and
+/// the steps that were taken
+///
+/// ### Attributes
+///    board (Board): The board after solving
+///    status (SolveStatus): Whether the board is solved, stalled, contradicting or unsound
+///    log (SolveLog): Every applied strategy, in order
+///    failure (Option<AuditFailure>): The strategy that removed or replaced a digit of the solution
+///    error (Option<BoardError>): The inconsistency of the board, when it contradicts itself
+pub struct SolveResult {
+    pub board: Board,
+    pub status: SolveStatus,
+    pub log: SolveLog,
+    pub failure: Option<AuditFailure>,
+    pub error: Option<BoardError>,
+}
+
+
+/// Gets the strategies of a solver for a board
+type GetStrategies = fn(&Board) -> Vec<Strategy>;
+
+
+/// ### Solver
+///
+/// Runs an ordered list of strategies on a board until a fixed point is reached.
+/// The strategies should be added from simple to complex. After any strategy made
+/// progress, the solver restarts from the simplest strategy. The solver stops when
+/// the board is solved, contains a contradiction or when no strategy changes the board.
+///
+/// Strategies that rely on the puzzle having a single solution are skipped, unless
+/// the solver is told to assume uniqueness.
+pub struct Solver {
+    strategies: Vec<(StrategyKind, GetStrategies, bool)>,
+    assume_uniqueness: bool,
+}
+
+impl Solver {
+    /// Creates a solver without any strategies
+    pub fn new() -> Solver {
+        return Solver {
+            strategies: vec![],
+            assume_uniqueness: false,
+        }
+    }
+
+    /// Adds a strategy to the end of the pipeline
+    ///
+    /// ### Returns
+    ///   Solver: the solver with the strategy added
+    pub fn with<S: SolveProbabilities>(mut self) -> Solver {
+        self.strategies.push((S::kind(), S::get_strategies, S::requires_uniqueness()));
+        return self
+    }
+
+    /// Gets the kinds of the strategies in the pipeline, in order
+    ///
+    /// ### Returns
+    ///   Vec<StrategyKind>: the kinds
+    pub fn kinds(&self) -> Vec<StrategyKind> {
+        return self.strategies.iter().map(|(kind, _, _)| *kind).collect()
+    }
+
+    /// Sets if the puzzles are known to have a single solution. Only then the
+    /// strategies that require uniqueness are used
+    ///
+    /// ### Arguments
+    ///   assume_uniqueness (bool): if the puzzles have a single solution
+    ///
+    /// ### Returns
+    ///   Solver: the adjusted solver
+    pub fn assume_uniqueness(mut self, assume_uniqueness: bool) -> Solver {
+        self.assume_uniqueness = assume_uniqueness;
+        return self
+    }
+
+    /// Runs the strategies on the board until it is solved, contradicting or stalled
+    ///
+    /// ### Arguments
+    ///   board (Board): the sudoku
+    ///
+    /// ### Returns
+    ///   SolveResult: the adjusted sudoku and the reason the solver stopped
+    pub fn solve(&self, board: Board) -> SolveResult {
+        return self.run(board, None)
+    }
+
+    /// Runs the strategies like solve, and checks every strategy against the known
+    /// solution before it is applied. The solver stops at the first strategy that
+    /// removes a digit of the solution or places a wrong digit
+    ///
+    /// ### Arguments
+    ///   board (Board): the sudoku
+    ///   solution (&str): the solution as a string of 81 digits
+    ///
+    /// ### Returns
+    ///   SolveResult: the adjusted sudoku, with the failure when a strategy was unsound
+    pub fn solve_audited(&self, board: Board, solution: &str) -> SolveResult {
+        return self.run(board, Some(&Board::from_string(solution)))
+    }
+
+    /// Runs the strategies on the board, audited when the solution is given
+    fn run(&self, mut board: Board, solution: Option<&Board>) -> SolveResult {
+        let mut log: SolveLog = SolveLog::new();
+
+        loop {
+            if let Err(error) = board.check() {
+                return SolveResult { board, status: SolveStatus::Contradiction, log, failure: None, error: Some(error) }
+            }
+
+            if board.solved() {
+                return SolveResult { board, status: SolveStatus::Solved, log, failure: None, error: None }
+            }
+
+            match self.step(&mut board, &mut log, solution) {
+                Ok(true) => continue,
+                Ok(false) => return SolveResult { board, status: SolveStatus::Stalled, log, failure: None, error: None },
+                Err(failure) => return SolveResult { board, status: SolveStatus::Unsound, log, failure: Some(failure), error: None },
+            }
+        }
+    }
+
+    /// Applies the strategies of the first solver that changes the board
+    ///
+    /// ### Arguments
+    ///   board (&mut Board): the sudoku
+    ///   log (&mut SolveLog): the log the applied strategies are added to
+    ///   solution (Option<&Board>): the solution the strategies are checked against
+    ///
+    /// ### Returns
+    ///   Result<bool, AuditFailure>: true if one of the strategies made progress, the
+    ///   failure when a strategy removes a digit of the solution
+    fn step(&self, board: &mut Board, log: &mut SolveLog, solution: Option<&Board>) -> Result<bool, AuditFailure> {
+        for (_, get_strategies, requires_uniqueness) in &self.strategies {
+            if *requires_uniqueness && !self.assume_uniqueness {
+                continue
+            }
+
+            let steps_before: usize = log.steps.len();
+
+            for strategy in get_strategies(board) {
+                if let Some(failure) = solution.and_then(|s| AuditFailure::check(board, &strategy, s)) {
+                    return Err(failure)
+                }
+
+                if let Some(step) = Step::record(board, strategy) {
+                    log.steps.push(step);
+                }
+            }
+
+            if log.steps.len() != steps_before {
+                return Ok(true)
+            }
+        }
+
+        return Ok(false)
+    }
+}
+
+/// The default pipeline, contains all strategies from simple to complex
+impl Default for Solver {
+    fn default() -> Solver {
+        return Solver::new()
+            .with::<NakedSingle>()
+            .with::<LastRemainingCell>()
+            .with::<HiddenSingle>()
+            .with::<Pointing>()
+            .with::<BoxLineReduction>()
+            .with::<Naked>()
+            .with::<Hidden>()
+            .with::<Fish>()
+            .with::<Skyscraper>()
+            .with::<TwoStringKite>()
+            .with::<FinnedFish>()
+            .with::<EmptyRectangle>()
+            .with::<TurbotFish>()
+            .with::<Coloring>()
+            .with::<XYWing>()
+            .with::<WWing>()
+            .with::<XYZWing>()
+            .with::<UniqueRectangle>()
+            .with::<WXYZWing>()
+            .with::<HiddenUniqueRectangle>()
+            .with::<XCycles>()
+            .with::<FrankenFish>()
+            .with::<SueDeCoq>()
+            .with::<Medusa>()
+            .with::<MutantFish>()
+            .with::<AlignedPairExclusion>()
+            .with::<BivalueUniversalGrave>()
+            .with::<AlsXZ>()
+            .with::<AlsXYWing>()
+            .with::<AlignedTripleExclusion>()
+            .with::<AlsChain>()
+            .with::<DeathBlossom>()
+            .with::<AlternatingInferenceChain>()
+            .with::<KrakenFish>()
+            .with::<CellForcingChain>()
+            .with::<DigitForcingChain>()
+            .with::<ContradictionForcingNet>()
+    }
+}
@@ -0,0 +1,109 @@
+use crate::board::*;
+use crate::pipeline::*;
+use crate::trace::*;
+use crate::utils::import_puzzles_from_file;
+
+
+/// The difficulty tier of a puzzle, derived from the score
+#[derive(Debug, PartialEq, PartialOrd)]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    Hard,
+    Expert,
+    Extreme,
+}
+
+impl Difficulty {
+    /// Gets the tier that belongs to a score
+    ///
+    /// ### Arguments
+    ///   score (u32): the weight of the hardest technique
+    ///
+    /// ### Returns
+    ///   Difficulty: the tier
+    pub fn from_score(score: u32) -> Difficulty {
+        return match score {
+            0..=12 => Difficulty::Easy,
+            13..=28 => Difficulty::Medium,
+            29..=40 => Difficulty::Hard,
+            41..=60 => Difficulty::Expert,
+            _ => Difficulty::Extreme,
+        }
+    }
+}
+
+
+/// ### Rating
+///
+/// The rating of a single puzzle. The score is the weight of the hardest
+/// technique that was needed, a puzzle that can't be solved is rated extreme.
+///
+/// ### Attributes
+///    score (u32): The weight of the hardest step
+///    difficulty (Difficulty): The tier that belongs to the score
+///    solved (bool): If the puzzle could be solved with the available strategies
+///    hardest (Option<Step>): The first step with the highest weight
+pub struct Rating {
+    pub score: u32,
+    pub difficulty: Difficulty,
+    pub solved: bool,
+    pub hardest: Option<Step>,
+}
+
+impl Rating {
+    /// Prints the score, the difficulty and the description of the hardest step
+    pub fn print(&self) {
+        println!("Score: {} ({:?})", self.score, self.difficulty);
+        if let Some(step) = &self.hardest {
+            println!("Hardest step: {}", step.description());
+        }
+    }
+}
+
+
+/// Rates a puzzle. The default Solver is used, which always applies the
+/// simplest technique that makes progress. The techniques that require a single
+/// solution are only used when uniqueness is assumed.
+///
+/// ### Arguments
+///   puzzle (&str): the puzzle as a string of 81 digits
+///   assume_uniqueness (bool): if the puzzle is known to have a single solution
+///
+/// ### Returns
+///   Rating: the rating of the puzzle
+pub fn rate(puzzle: &str, assume_uniqueness: bool) -> Rating {
+    let result: SolveResult = Solver::default()
+        .assume_uniqueness(assume_uniqueness)
+        .solve(Board::from_string(puzzle));
+    let solved: bool = result.status == SolveStatus::Solved;
+
+    let hardest: Option<Step> = result.log.steps
+        .into_iter()
+        .rev()
+        .max_by_key(|step| step.kind.weight());
+
+    let score: u32 = hardest.as_ref().map_or(0, |step| step.kind.weight());
+
+    return Rating {
+        score,
+        difficulty: if solved { Difficulty::from_score(score) } else { Difficulty::Extreme },
+        solved,
+        hardest,
+    }
+}
+
+
+/// Rates all puzzles in puzzles.txt
+///
+/// ### Arguments
+///   assume_uniqueness (bool): if the puzzles are known to have a single solution
+///
+/// ### Returns
+///   Vec<Rating>: the ratings, in the order of the file
+pub fn rate_puzzles_from_file(assume_uniqueness: bool) -> Vec<Rating> {
+    return import_puzzles_from_file()
+        .iter()
+        .map(|puzzle| rate(&puzzle[0], assume_uniqueness))
+        .collect()
+}
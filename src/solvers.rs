@@ -1,39 +1,44 @@
-#![allow(clippy::needless_return)]
-
 use std::collections::{HashMap, HashSet};
-use std::vec;
-use crate::lib::*;
+use crate::board::*;
 
-use itertools::{Combinations, Itertools, rev};
+use itertools::Itertools;
 
 
-#[derive(Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum Orientation {
     Row,
     Column,
     Block,
 }
 
-// This is an easy reference to return no strategies
-const NO_STRATEGIES: Vec<Strategy> = vec![];
-
 pub trait SolveProbabilities {
-    fn name() -> String;
-    
-    fn orientations() -> Vec<Orientation>;
+    fn kind() -> StrategyKind;
+
+    /// The orientations of the houses the logic runs for. Strategies that don't look
+    /// at a single house have none, and override get_strategies instead
+    fn orientations() -> Vec<Orientation> {
+        return vec![]
+    }
+
+    /// If the strategy is only valid for puzzles with a single solution
+    fn requires_uniqueness() -> bool {
+        return false
+    }
 
-    fn logic(board: &Board, orientation: &Orientation, index: u8) -> Vec<Strategy>;
+    fn logic(_board: &Board, _orientation: &Orientation, _index: u8) -> Vec<Strategy> {
+        return vec![]
+    }
 
     /// Gets the strategy for the solver and applies the strategies to the board. Runs
     /// the solver for its own orientations and for index 0-8
     ///
     /// ### Arguments
-    ///     board (mut Board): the sudoku
+    ///   board (mut Board): the sudoku
     ///
     /// ### Returns
-    ///     Board: the adjusted sudoku
+    ///   Board: the adjusted sudoku
     fn get_and_apply_strategies(mut board: Board) -> Board {
-        let mut strategies: Vec<Strategy> = Self::get_strategies(&board);
+        let strategies: Vec<Strategy> = Self::get_strategies(&board);
 
         for s in strategies {
             board.apply_strategy(s)
@@ -46,7 +51,7 @@ pub trait SolveProbabilities {
         let mut strategies: Vec<Strategy> = vec![];
         for orientation in Self::orientations() {
             for i in 0..9 {
-                strategies.extend(Self::logic(&board, &orientation, i));
+                strategies.extend(Self::logic(board, &orientation, i));
             }
         }
 
@@ -73,6 +78,63 @@ pub trait SolveProbabilities {
             Orientation::Block => { board.block(index) },
         };
     }
+
+    /// Creates the house that belongs to the orientation and index
+    fn create_house(orientation: &Orientation, index: u8) -> House {
+        return match orientation {
+            Orientation::Row => { House::Row(index) },
+            Orientation::Column => { House::Column(index) },
+            Orientation::Block => { House::Block(index) },
+        };
+    }
+}
+
+
+/// NakedSingle places the value of a cell when the values that are solved in its
+/// row, column and block leave a single probability
+///
+/// The index is used as the block of the cell
+pub struct NakedSingle;
+
+impl SolveProbabilities for NakedSingle {
+    fn kind() -> StrategyKind {
+        return StrategyKind::NakedSingle
+    }
+
+    fn orientations() -> Vec<Orientation> {
+        return vec![Orientation::Block]
+    }
+
+    fn logic(board: &Board, orientation: &Orientation, index: u8) -> Vec<Strategy> {
+        let mut strategies: Vec<Strategy> = vec![];
+
+        for c in Self::create_subset(board, orientation, index).missing() {
+            let solved: HashSet<u8> = [board.row(c.row()), board.column(c.column()), board.block(c.block())]
+                .iter()
+                .flat_map(|subset| subset.values_solved())
+                .collect();
+
+            let remaining: Vec<u8> = c.probabilities
+                .iter()
+                .filter(|p| !solved.contains(p))
+                .copied()
+                .collect();
+
+            if remaining.len() != 1 {
+                continue
+            }
+
+            strategies.push(
+                Strategy::new(
+                    Self::kind(),
+                    HashMap::new()
+                ).with_place(HashMap::from([(c.index, remaining[0])]))
+                .with_pattern(vec![c.index], remaining)
+            );
+        }
+
+        return strategies
+    }
 }
 
 
@@ -82,8 +144,8 @@ pub trait SolveProbabilities {
 pub struct LastRemainingCell;
 
 impl SolveProbabilities for LastRemainingCell {
-    fn name() -> String {
-        return String::from("LastRemainingCell")
+    fn kind() -> StrategyKind {
+        return StrategyKind::LastRemainingCell
     }
     
     fn orientations() -> Vec<Orientation> {
@@ -110,15 +172,68 @@ impl SolveProbabilities for LastRemainingCell {
                 .cloned()
                 .collect();
 
+            // The solved cells that hold the probabilities
+            let solved: Vec<u8> = subset.cells
+                .iter()
+                .filter(|c| c.solved() && probabilities_to_delete.contains(&c.probabilities[0]))
+                .map(|c| c.index)
+                .collect();
+            let digits: Vec<u8> = probabilities_to_delete.iter().copied().collect();
+
             strategies.push(
                 Strategy::new(
-                    Self::name(),
+                    Self::kind(),
                     HashMap::from(
                         [(i, probabilities_to_delete)]
                     )
-                )
+                ).with_houses(vec![Self::create_house(orientation, index)], vec![])
+                .with_pattern(solved, digits)
+            );
+        }
+        return strategies
+    }
+}
+
+
+/// HiddenSingle places a digit in a cell when it is the only cell of a row, column
+/// or block in which the digit is probable
+///
+/// The index is used as the row, column or block
+pub struct HiddenSingle;
+
+impl SolveProbabilities for HiddenSingle {
+    fn kind() -> StrategyKind {
+        return StrategyKind::HiddenSingle
+    }
+
+    fn orientations() -> Vec<Orientation> {
+        return vec![Orientation::Row, Orientation::Column, Orientation::Block]
+    }
+
+    fn logic(board: &Board, orientation: &Orientation, index: u8) -> Vec<Strategy> {
+        let mut strategies: Vec<Strategy> = vec![];
+        let subset: Subset = Self::create_subset(board, orientation, index);
+
+        for digit in (1..=9).filter(|d| !subset.contains(d)) {
+            let cells: Vec<Cell> = subset.missing()
+                .into_iter()
+                .filter(|c| c.contains(&digit))
+                .collect();
+
+            if cells.len() != 1 {
+                continue
+            }
+
+            strategies.push(
+                Strategy::new(
+                    Self::kind(),
+                    HashMap::new()
+                ).with_place(HashMap::from([(cells[0].index, digit)]))
+                .with_houses(vec![Self::create_house(orientation, index)], vec![])
+                .with_pattern(vec![cells[0].index], vec![digit])
             );
         }
+
         return strategies
     }
 }
@@ -127,8 +242,8 @@ impl SolveProbabilities for LastRemainingCell {
 pub struct Naked;
 
 impl SolveProbabilities for Naked {
-    fn name() -> String {
-        return String::from("LastRemainingCell")
+    fn kind() -> StrategyKind {
+        return StrategyKind::Naked
     }
     
     fn orientations() -> Vec<Orientation> {
@@ -163,8 +278,8 @@ impl SolveProbabilities for Naked {
                 // The same goes for the rest
                 other_cells = subset.indices
                     .iter()
-                    .filter(|index| !naked.contains(&index))
-                    .map(|index| *index)
+                    .filter(|index| !naked.contains(index))
+                    .copied()
                     .collect();
 
 
@@ -175,9 +290,10 @@ impl SolveProbabilities for Naked {
 
                 strategies.push(
                     Strategy::new(
-                        Self::name(),
+                        Self::kind(),
                         hashmap
-                    )
+                    ).with_houses(vec![Self::create_house(orientation, index)], vec![])
+                    .with_pattern(naked.clone(), unique_numbers.iter().copied().collect())
                 )
 
                 // board.remove_probabilities_from_cells(other_cells,
@@ -192,8 +308,8 @@ impl SolveProbabilities for Naked {
 pub struct Hidden;
 
 impl SolveProbabilities for Hidden {
-    fn name() -> String {
-        return String::from("Hidden")
+    fn kind() -> StrategyKind {
+        return StrategyKind::Hidden
     }
 
     fn orientations() -> Vec<Orientation> {
@@ -244,7 +360,7 @@ impl SolveProbabilities for Hidden {
                 let mut hashmap: HashMap<u8, HashSet<u8>> = HashMap::new();
 
                 for c in combination {
-                    let set: HashSet<u8> = c.as_set().difference(&possibly_hidden).map(|p| *p).collect();
+                    let set: HashSet<u8> = c.as_set().difference(&possibly_hidden).copied().collect();
                     hashmap.insert(c.index, set);
                 }
 
@@ -254,9 +370,10 @@ impl SolveProbabilities for Hidden {
                 // }
                 strategies.push(
                     Strategy::new(
-                        Self::name(),
+                        Self::kind(),
                         hashmap
-                    )
+                    ).with_houses(vec![Self::create_house(orientation, index)], vec![])
+                    .with_pattern(indices_combinations.clone(), possibly_hidden.into_iter().collect())
                 )
 
             }
@@ -272,8 +389,8 @@ impl SolveProbabilities for Hidden {
 pub struct Pointing;
 
 impl SolveProbabilities for Pointing {
-    fn name() -> String {
-        String::from("LastRemainingCell")
+    fn kind() -> StrategyKind {
+        return StrategyKind::Pointing
     }
     
     fn orientations() -> Vec<Orientation> {
@@ -281,34 +398,18 @@ impl SolveProbabilities for Pointing {
     }
 
     fn logic(board: &Board, orientation: &Orientation, index: u8) -> Vec<Strategy> {
-        let subset: Subset;
-        let missing: Vec<Cell>;
         let mut strategies: Vec<Strategy> = vec![];
         let mut missing_line: Vec<u8>;
 
-        let get_row_or_colum_index: fn(&Cell) -> u8;
-        let get_line: fn(&Board, u8) -> Subset;
-
-        // Create function that have gets rows/columns respective to the orientation
-        match orientation {
-            Orientation::Row => {
-                get_row_or_colum_index = |c: &Cell | -> u8 {c.row()};
-                get_line = |b: &Board, i: u8 | -> Subset {b.row(i)};
-            }
-            Orientation::Column => {
-                get_row_or_colum_index = |c: &Cell | -> u8 {c.column()};
-                get_line = |b: &Board, i: u8 | -> Subset {b.column(i)};
-            },
-            _ => panic!("Only Row/Column orientation is allowed with Pointing strategy")
-        };
+        let get_row_or_colum_index: LineIndex = line_index(orientation);
 
-        subset = board.block(index);
+        let subset: Subset = board.block(index);
 
         if subset.is_solved() {
             return strategies
         }
 
-        missing = subset.missing();
+        let missing: Vec<Cell> = subset.missing();
         let values_solved: HashSet<u8> = subset.values_solved();
 
         for p in 1..=9 {
@@ -331,18 +432,25 @@ impl SolveProbabilities for Pointing {
                 continue
             }
 
-            let hashmap: HashMap<u8, HashSet<u8>> = get_line(board, missing_line[0])
+            let hashmap: HashMap<u8, HashSet<u8>> = Self::create_subset(board, orientation, missing_line[0])
                 .cells
                 .iter()
                 .filter(|c| c.block() != index && !c.solved())
                 .map(|c| (c.index, HashSet::from([p])))
                 .collect();
 
+            let pointing: Vec<u8> = missing
+                .iter()
+                .filter(|c| c.contains(&p))
+                .map(|c| c.index)
+                .collect();
+
             strategies.push(
                 Strategy::new(
-                    Self::name(),
+                    Self::kind(),
                     hashmap
-                )
+                ).with_houses(vec![House::Block(index)], vec![Self::create_house(orientation, missing_line[0])])
+                .with_pattern(pointing, vec![p])
             );
         }
 
@@ -354,8 +462,8 @@ impl SolveProbabilities for Pointing {
 pub struct BoxLineReduction;
 
 impl SolveProbabilities for BoxLineReduction {
-    fn name() -> String {
-        return String::from("BoxLineReduction")
+    fn kind() -> StrategyKind {
+        return StrategyKind::BoxLineReduction
     }
 
     fn orientations() -> Vec<Orientation> {
@@ -370,18 +478,7 @@ impl SolveProbabilities for BoxLineReduction {
             return strategies
         }
 
-        let get_row_or_colum_index: fn(&Cell) -> u8;
-
-        // Create function that have gets rows/columns respective to the orientation
-        match orientation {
-            Orientation::Row => {
-                get_row_or_colum_index = |c: &Cell | -> u8 {c.row()};
-            }
-            Orientation::Column => {
-                get_row_or_colum_index = |c: &Cell | -> u8 {c.column()};
-            },
-            _ => panic!("Only Row/Column orientation is allowed with Pointing strategy")
-        };
+        let get_row_or_colum_index: LineIndex = line_index(orientation);
 
         for p in 1..=9 {
             let block_indices: Vec<u8> = subset.missing()
@@ -392,7 +489,7 @@ impl SolveProbabilities for BoxLineReduction {
 
             if block_indices
                 .iter()
-                .map(|n| *n)
+                .copied()
                 .unique()
                 .collect::<Vec<u8>>()
                 .len() != 1 || block_indices.len() <= 1 {
@@ -408,7 +505,7 @@ impl SolveProbabilities for BoxLineReduction {
             let _strategy: HashMap<u8, HashSet<u8>> = block
                 .cells
                 .iter()
-                .filter(|c| get_row_or_colum_index(&c) == index && c.contains(&p))
+                .filter(|c| get_row_or_colum_index(c) == index && c.contains(&p))
                 .map(|c| (c.index, HashSet::from([p])))
                 .collect();
 
@@ -423,13 +520,1942 @@ impl SolveProbabilities for BoxLineReduction {
                 continue
             }
 
+            let locked: Vec<u8> = subset.missing()
+                .iter()
+                .filter(|c| c.contains(&p))
+                .map(|c| c.index)
+                .collect();
+
             strategies.push(
                 Strategy::new(
-                    Self::name(),
+                    Self::kind(),
                     hashmap.clone()
-                )
+                ).with_houses(vec![Self::create_house(orientation, index)], vec![House::Block(block_indices[0])])
+                .with_pattern(locked, vec![p])
             );
         }
         return strategies
     }
 }
+
+
+/// SueDeCoq uses the intersection of a line and a block
+///
+/// Two or three cells of the intersection hold at least two more probabilities
+/// than cells. When they combine with a set of cells in the rest of the line and a
+/// set of cells in the rest of the block, which have no probability in common, into
+/// as many digits as cells, every digit is placed exactly once in those cells. The
+/// digits of the line set are removed from the rest of the line, the digits of the
+/// block set from the rest of the block, and the other digits from both.
+///
+/// The index is used as the row or column
+pub struct SueDeCoq;
+
+impl SolveProbabilities for SueDeCoq {
+    fn kind() -> StrategyKind {
+        return StrategyKind::SueDeCoq
+    }
+
+    fn orientations() -> Vec<Orientation> {
+        return vec![Orientation::Row, Orientation::Column]
+    }
+
+    fn logic(board: &Board, orientation: &Orientation, index: u8) -> Vec<Strategy> {
+        let mut strategies: Vec<Strategy> = vec![];
+        let get_row_or_colum_index: LineIndex = line_index(orientation);
+
+        let blocks: Vec<u8> = Self::create_subset(board, orientation, index)
+            .cells
+            .iter()
+            .map(|c| c.block())
+            .unique()
+            .collect();
+
+        for block in blocks {
+            let (intersection, line, rest) = intersection(board, orientation, index, block);
+
+            for n in 2..=3 {
+                for cells in intersection.iter().combinations(n) {
+                    let digits: HashSet<u8> = cells.iter().flat_map(|c| c.as_set()).collect();
+
+                    if digits.len() < n + 2 {
+                        continue
+                    }
+
+                    for (line_set, line_digits) in candidate_sets(&line, &digits) {
+                        for (block_set, block_digits) in candidate_sets(&rest, &digits) {
+                            if !line_digits.is_disjoint(&block_digits) {
+                                continue
+                            }
+
+                            let all: HashSet<u8> = digits
+                                .iter()
+                                .chain(line_digits.iter())
+                                .chain(block_digits.iter())
+                                .copied()
+                                .collect();
+
+                            if all.len() != n + line_set.len() + block_set.len() {
+                                continue
+                            }
+
+                            let used: Vec<u8> = cells
+                                .iter()
+                                .map(|c| c.index)
+                                .chain(line_set.iter().copied())
+                                .chain(block_set.iter().copied())
+                                .collect();
+
+                            let hashmap: HashMap<u8, HashSet<u8>> = board.cells
+                                .iter()
+                                .filter(|c| !c.solved() && !used.contains(&c.index))
+                                .map(|c| {
+                                    let in_line: bool = c.block() != block && get_row_or_colum_index(c) == index;
+                                    let in_block: bool = c.block() == block;
+                                    let remove: HashSet<u8> = all
+                                        .iter()
+                                        .filter(|d| {
+                                            (in_line && !block_digits.contains(d))
+                                            || (in_block && !line_digits.contains(d))
+                                            || (c.block() == block && get_row_or_colum_index(c) == index)
+                                        })
+                                        .filter(|d| c.contains(d))
+                                        .copied()
+                                        .collect();
+                                    (c.index, remove)
+                                })
+                                .filter(|(_, remove)| !remove.is_empty())
+                                .collect();
+
+                            if hashmap.is_empty() {
+                                continue
+                            }
+
+                            strategies.push(
+                                Strategy::new(
+                                    Self::kind(),
+                                    hashmap
+                                ).with_houses(
+                                    vec![Self::create_house(orientation, index), House::Block(block)],
+                                    vec![]
+                                ).with_pattern(used, all.into_iter().collect())
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        return strategies
+    }
+}
+
+
+/// Gets the unsolved cells of the intersection of a line and a block, of the rest
+/// of the line and of the rest of the block
+///
+/// ### Arguments
+///   board (&Board): the sudoku
+///   orientation (&Orientation): if the line is a row or column
+///   line (u8): the index of the line
+///   block (u8): the index of the block
+///
+/// ### Returns
+///   (Vec<Cell>, Vec<Cell>, Vec<Cell>): the intersection, the rest of the line and the rest of the block
+fn intersection(board: &Board, orientation: &Orientation, line: u8, block: u8) -> (Vec<Cell>, Vec<Cell>, Vec<Cell>) {
+    let get_row_or_colum_index: LineIndex = line_index(orientation);
+
+    let (intersection, line_rest): (Vec<Cell>, Vec<Cell>) = SueDeCoq::create_subset(board, orientation, line)
+        .missing()
+        .into_iter()
+        .partition(|c| c.block() == block);
+
+    let block_rest: Vec<Cell> = board.block(block)
+        .missing()
+        .into_iter()
+        .filter(|c| get_row_or_colum_index(c) != line)
+        .collect();
+
+    return (intersection, line_rest, block_rest)
+}
+
+
+/// Gets the sets of cells of which every cell has a probability in common with the
+/// digits, together with the combined probabilities of the set
+fn candidate_sets(cells: &[Cell], digits: &HashSet<u8>) -> Vec<(Vec<u8>, HashSet<u8>)> {
+    let linked: Vec<&Cell> = cells
+        .iter()
+        .filter(|c| !c.as_set().is_disjoint(digits))
+        .collect();
+
+    return linked
+        .into_iter()
+        .powerset()
+        .filter(|set| !set.is_empty())
+        .map(|set| (
+            set.iter().map(|c| c.index).collect(),
+            set.iter().flat_map(|c| c.as_set()).collect(),
+        ))
+        .collect()
+}
+
+
+/// Fish uses the lines in which a digit is limited to a few positions
+///
+/// When a digit in n rows (the base) can only be placed in the same n columns
+/// (the cover), each of those columns gets the digit in one of the base rows. The
+/// digit can be removed from the rest of the cover columns. This is an X-Wing for
+/// two, a Swordfish for three and a Jellyfish for four lines. The same goes for
+/// columns as the base and rows as the cover.
+///
+/// Every digit is searched on its own, with the rows and with the columns as the base
+pub struct Fish;
+
+impl SolveProbabilities for Fish {
+    fn kind() -> StrategyKind {
+        return StrategyKind::Fish
+    }
+
+    fn get_strategies(board: &Board) -> Vec<Strategy> {
+        let mut strategies: Vec<Strategy> = vec![];
+
+        for orientation in &[Orientation::Row, Orientation::Column] {
+            for digit in 1..=9 {
+                let (get_base_index, _, cover_orientation) = fish_orientation(orientation);
+                let lines: Vec<(u8, HashSet<u8>)> = fish_lines(board, orientation, digit)
+                    .into_iter()
+                    .filter(|(_, positions)| positions.len() >= 2)
+                    .collect();
+
+                for size in 2..=4 {
+                    for base in lines.iter().filter(|(_, positions)| positions.len() <= size).combinations(size) {
+                        let cover: HashSet<u8> = base
+                            .iter()
+                            .flat_map(|(_, positions)| positions.iter().copied())
+                            .collect();
+
+                        if cover.len() != size {
+                            continue
+                        }
+
+                        let base_indices: Vec<u8> = base.iter().map(|(line, _)| *line).collect();
+
+                        let hashmap: HashMap<u8, HashSet<u8>> = cover
+                            .iter()
+                            .flat_map(|line| Self::create_subset(board, &cover_orientation, *line).missing())
+                            .filter(|c| c.contains(&digit) && !base_indices.contains(&get_base_index(c)))
+                            .map(|c| (c.index, HashSet::from([digit])))
+                            .collect();
+
+                        if hashmap.is_empty() {
+                            continue
+                        }
+
+                        let base_cells: Vec<u8> = base_indices
+                            .iter()
+                            .flat_map(|line| Self::create_subset(board, orientation, *line).missing())
+                            .filter(|c| c.contains(&digit))
+                            .map(|c| c.index)
+                            .collect();
+
+                        strategies.push(
+                            Strategy::new(
+                                Self::kind(),
+                                hashmap
+                            ).with_houses(
+                                base_indices.iter().map(|i| Self::create_house(orientation, *i)).collect(),
+                                cover.iter().sorted().map(|i| Self::create_house(&cover_orientation, *i)).collect()
+                            ).with_pattern(base_cells, vec![digit])
+                        );
+                    }
+                }
+
+            }
+        }
+
+        return strategies
+    }
+}
+
+
+
+/// FinnedFish is a fish with extra probabilities, the fins, in the base lines
+///
+/// Either one of the fins is the digit, or the base and cover lines form a regular
+/// fish. The digit can therefore only be removed from the cover lines in cells that
+/// see every fin. When a base line only has a single probability left besides the
+/// fins, the fish is called sashimi, which is found the same way.
+///
+/// Every digit is searched on its own, with the rows and with the columns as the base
+pub struct FinnedFish;
+
+impl SolveProbabilities for FinnedFish {
+    fn kind() -> StrategyKind {
+        return StrategyKind::FinnedFish
+    }
+
+    fn get_strategies(board: &Board) -> Vec<Strategy> {
+        let mut strategies: Vec<Strategy> = vec![];
+
+        for orientation in &[Orientation::Row, Orientation::Column] {
+            for digit in 1..=9 {
+                let (get_base_index, get_cover_index, cover_orientation) = fish_orientation(orientation);
+                let lines: Vec<(u8, HashSet<u8>)> = fish_lines(board, orientation, digit);
+
+                for size in 2..=4 {
+                    for base in lines.iter().combinations(size) {
+                        let positions: HashSet<u8> = base
+                            .iter()
+                            .flat_map(|(_, positions)| positions.iter().copied())
+                            .collect();
+
+                        // Without fins it is a regular fish. Fins that can all be seen from a
+                        // cover line lie in one block, so they span at most three positions
+                        if positions.len() <= size || positions.len() > size + 3 {
+                            continue
+                        }
+
+                        let base_indices: Vec<u8> = base.iter().map(|(line, _)| *line).collect();
+                        let base_cells: Vec<Cell> = base_indices
+                            .iter()
+                            .flat_map(|line| Self::create_subset(board, orientation, *line).missing())
+                            .filter(|c| c.contains(&digit))
+                            .collect();
+
+                        for cover in positions.iter().copied().combinations(size) {
+                            // Every base line should still be part of the fish
+                            if base.iter().any(|(_, p)| p.iter().all(|position| !cover.contains(position))) {
+                                continue
+                            }
+
+                            let fins: Vec<&Cell> = base_cells
+                                .iter()
+                                .filter(|c| !cover.contains(&get_cover_index(c)))
+                                .collect();
+
+                            if fins.iter().map(|c| c.block()).unique().count() != 1 {
+                                continue
+                            }
+
+                            let hashmap: HashMap<u8, HashSet<u8>> = cover
+                                .iter()
+                                .flat_map(|line| Self::create_subset(board, &cover_orientation, *line).missing())
+                                .filter(|c| c.contains(&digit) && !base_indices.contains(&get_base_index(c)))
+                                .filter(|c| fins.iter().all(|fin| fin.sees(c)))
+                                .map(|c| (c.index, HashSet::from([digit])))
+                                .collect();
+
+                            if hashmap.is_empty() {
+                                continue
+                            }
+
+                            strategies.push(
+                                Strategy::new(
+                                    Self::kind(),
+                                    hashmap
+                                ).with_houses(
+                                    base_indices.iter().map(|i| Self::create_house(orientation, *i)).collect(),
+                                    cover.iter().sorted().map(|i| Self::create_house(&cover_orientation, *i)).collect()
+                                ).with_fins(
+                                    fins.iter().map(|c| c.index).collect()
+                                ).with_pattern(base_cells.iter().map(|c| c.index).collect(), vec![digit])
+                            );
+                        }
+                    }
+                }
+
+            }
+        }
+
+        return strategies
+    }
+}
+
+
+/// Gets the index of the line a cell is in
+type LineIndex = fn(&Cell) -> u8;
+
+/// Gets the function for the index of the row or column of a cell, respective to
+/// the orientation
+fn line_index(orientation: &Orientation) -> LineIndex {
+    return match orientation {
+        Orientation::Row => |c: &Cell| -> u8 {c.row()},
+        Orientation::Column => |c: &Cell| -> u8 {c.column()},
+        _ => panic!("Only Row/Column orientation is allowed for a line")
+    };
+}
+
+/// Gets the functions for the index of the base and the cover line of a cell, and
+/// the orientation of the cover lines for a fish with the given base orientation
+fn fish_orientation(orientation: &Orientation) -> (LineIndex, LineIndex, Orientation) {
+    // The cover lines are always perpendicular to the base lines
+    return match orientation {
+        Orientation::Row => (|c: &Cell| -> u8 {c.row()}, |c: &Cell| -> u8 {c.column()}, Orientation::Column),
+        Orientation::Column => (|c: &Cell| -> u8 {c.column()}, |c: &Cell| -> u8 {c.row()}, Orientation::Row),
+        _ => panic!("Only Row/Column orientation is allowed with Fish strategy")
+    };
+}
+
+/// Gets for every base line, in which the digit isn't solved, the index of the
+/// cover lines in which the digit is probable
+fn fish_lines(board: &Board, orientation: &Orientation, digit: u8) -> Vec<(u8, HashSet<u8>)> {
+    let (_, get_cover_index, _) = fish_orientation(orientation);
+
+    let mut lines: Vec<(u8, HashSet<u8>)> = vec![];
+    for line in 0..9 {
+        let subset: Subset = Fish::create_subset(board, orientation, line);
+        if subset.contains(&digit) {
+            continue
+        }
+
+        let positions: HashSet<u8> = subset.missing()
+            .iter()
+            .filter(|c| c.contains(&digit))
+            .map(get_cover_index)
+            .collect();
+
+        if !positions.is_empty() {
+            lines.push((line, positions));
+        }
+    }
+
+    return lines
+}
+
+
+/// Gets the orientation of a house
+fn house_orientation(house: &House) -> Orientation {
+    return match house {
+        House::Row(_) => Orientation::Row,
+        House::Column(_) => Orientation::Column,
+        House::Block(_) => Orientation::Block,
+    }
+}
+
+
+/// Gets the houses of a cell
+pub fn cell_houses(cell: &Cell) -> [House; 3] {
+    return [House::Row(cell.row()), House::Column(cell.column()), House::Block(cell.block())]
+}
+
+
+/// Gets every fish of a digit in which the base and cover houses have one of the
+/// given orientations. The base houses can't share a probability
+/// of the digit, and every probability in the base houses that isn't in one of the
+/// cover houses is a fin
+///
+/// ### Arguments
+///   board (&Board): the sudoku
+///   base_orientations (&[Orientation]): the orientations the base houses can have
+///   cover_orientations (&[Orientation]): the orientations the cover houses can have
+///   digit (u8): the digit
+///   max_fins (usize): the maximum number of fins
+///   seen_fins (bool): if a probability of the digit outside the base should see all fins
+///
+/// ### Returns
+///   Vec<(Vec<House>, Vec<House>, Vec<u8>)>: the base houses, cover houses and fins of every
+///       fish, the smallest fish first
+pub fn mixed_fish(
+    board: &Board,
+    base_orientations: &[Orientation],
+    cover_orientations: &[Orientation],
+    digit: u8,
+    max_fins: usize,
+    seen_fins: bool,
+) -> Vec<(Vec<House>, Vec<House>, Vec<u8>)> {
+    let houses: Vec<(House, Vec<u8>)> = (0..9)
+        .flat_map(|i| [House::Row(i), House::Column(i), House::Block(i)])
+        .filter(|h| base_orientations.contains(&house_orientation(h)))
+        .map(|h| (h, house_subset(board, &h)))
+        .filter(|(_, subset)| !subset.contains(&digit))
+        .map(|(h, subset)| (h, subset.missing().iter().filter(|c| c.contains(&digit)).map(|c| c.index).collect()))
+        .filter(|(_, cells): &(House, Vec<u8>)| cells.len() >= 2)
+        .collect();
+
+    let mut fish: HashSet<(Vec<House>, Vec<House>, Vec<u8>)> = HashSet::new();
+
+    for size in 2..=4 {
+        for base in houses.iter().combinations(size) {
+            let cells: Vec<u8> = base.iter().flat_map(|(_, cells)| cells.iter().copied()).sorted().collect();
+
+            if cells.iter().duplicates().next().is_some() {
+                continue
+            }
+
+            let search: FishSearch = FishSearch {
+                board,
+                cells: &cells,
+                base: base.iter().map(|(h, _)| *h).collect(),
+                orientations: cover_orientations,
+                size,
+                seen_fins,
+            };
+
+            let peers: Vec<u8> = board.cells
+                .iter()
+                .filter(|c| !c.solved() && c.contains(&digit) && !cells.contains(&c.index))
+                .map(|c| c.index)
+                .collect();
+
+            let mut covers: Vec<Vec<House>> = vec![];
+            search.cover(vec![], max_fins, peers, 0, &mut covers);
+
+            for cover in covers {
+                let fins: Vec<u8> = cells
+                    .iter()
+                    .filter(|i| !cover.iter().any(|h| cell_houses(&board.cells[**i as usize]).contains(h)))
+                    .copied()
+                    .collect();
+
+                fish.insert((search.base.clone(), cover.into_iter().sorted().collect(), fins));
+            }
+        }
+    }
+
+    // The smallest fish with the fewest fins come first
+    return fish
+        .into_iter()
+        .sorted_by(|a, b| (a.0.len(), a.2.len(), a).cmp(&(b.0.len(), b.2.len(), b)))
+        .collect()
+}
+
+
+/// FishSearch
+///
+/// Picks the cover houses for the probabilities of the base houses of a fish
+///
+/// ### Attributes
+///    board (&Board): The sudoku
+///    cells (&[u8]): The cells of the base houses in which the digit is probable
+///    base (Vec<House>): The base houses
+///    orientations (&[Orientation]): The orientations the cover houses can have
+///    size (usize): The number of cover houses
+///    seen_fins (bool): If a probability of the digit outside the base should see all fins
+struct FishSearch<'a> {
+    board: &'a Board,
+    cells: &'a [u8],
+    base: Vec<House>,
+    orientations: &'a [Orientation],
+    size: usize,
+    seen_fins: bool,
+}
+
+impl FishSearch<'_> {
+    /// Covers the probabilities one at a time. A probability is covered by one of its
+    /// houses, or is left as a fin
+    ///
+    /// ### Arguments
+    ///   cover (Vec<House>): the cover houses that are picked so far
+    ///   fins (usize): the number of fins that can still be left
+    ///   peers (Vec<u8>): the probabilities outside the base that can be removed so far
+    ///   position (usize): the probability that is covered next
+    ///   covers (&mut Vec<Vec<House>>): the cover houses of every fish that is found
+    fn cover(&self, cover: Vec<House>, fins: usize, peers: Vec<u8>, position: usize, covers: &mut Vec<Vec<House>>) {
+        if position == self.cells.len() {
+            // A fish without a probability to remove is left out
+            let removes: bool = peers
+                .iter()
+                .any(|i| cell_houses(&self.board.cells[*i as usize]).iter().any(|h| cover.contains(h)));
+
+            if cover.len() == self.size && removes {
+                covers.push(cover);
+            }
+            return
+        }
+
+        let cell: &Cell = &self.board.cells[self.cells[position] as usize];
+        let houses: [House; 3] = cell_houses(cell);
+
+        if houses.iter().any(|h| cover.contains(h)) {
+            return self.cover(cover, fins, peers, position + 1, covers)
+        }
+
+        if cover.len() < self.size {
+            for house in houses.iter().filter(|h| !self.base.contains(h) && self.orientations.contains(&house_orientation(h))) {
+                let mut next: Vec<House> = cover.clone();
+                next.push(*house);
+                self.cover(next, fins, peers.clone(), position + 1, covers);
+            }
+        }
+
+        if fins == 0 {
+            return
+        }
+
+        let peers: Vec<u8> = peers
+            .into_iter()
+            .filter(|i| !self.seen_fins || self.board.cells[*i as usize].sees(cell))
+            .collect();
+
+        if !peers.is_empty() {
+            self.cover(cover, fins - 1, peers, position + 1, covers);
+        }
+    }
+}
+
+
+/// Gets the probabilities of a digit in the cover houses of a fish that aren't in
+/// one of the base houses
+pub fn fish_targets(board: &Board, base: &[House], cover: &[House], digit: u8) -> Vec<u8> {
+    return board.cells
+        .iter()
+        .filter(|c| !c.solved() && c.contains(&digit))
+        .filter(|c| {
+            let houses: [House; 3] = cell_houses(c);
+            cover.iter().any(|h| houses.contains(h)) && !base.iter().any(|h| houses.contains(h))
+        })
+        .map(|c| c.index)
+        .collect()
+}
+
+
+/// Gets the probabilities of a digit in the base houses of a fish
+pub fn fish_cells(board: &Board, base: &[House], digit: u8) -> Vec<u8> {
+    return board.cells
+        .iter()
+        .filter(|c| !c.solved() && c.contains(&digit))
+        .filter(|c| cell_houses(c).iter().any(|h| base.contains(h)))
+        .map(|c| c.index)
+        .collect()
+}
+
+
+/// Gets the strategies of the mixed fish that are not of a simpler kind
+///
+/// ### Arguments
+///   board (&Board): the sudoku
+///   base_orientations (&[Orientation]): the orientations the base houses can have
+///   cover_orientations (&[Orientation]): the orientations the cover houses can have
+///   digit (u8): the digit
+///   kind (StrategyKind): the kind of the strategy
+///   is_kind (fn(&[House], &[House]) -> bool): checks if the base and cover houses are of the kind
+///
+/// ### Returns
+///   Vec<Strategy>: the strategies
+fn mixed_fish_logic(
+    board: &Board,
+    base_orientations: &[Orientation],
+    cover_orientations: &[Orientation],
+    digit: u8,
+    kind: StrategyKind,
+    is_kind: fn(&[House], &[House]) -> bool,
+) -> Vec<Strategy> {
+    let mut strategies: Vec<Strategy> = vec![];
+    let mut found: HashSet<Vec<u8>> = HashSet::new();
+
+    for (base, cover, fins) in mixed_fish(board, base_orientations, cover_orientations, digit, 3, true) {
+        if !is_kind(&base, &cover) {
+            continue
+        }
+
+        let targets: Vec<u8> = fish_targets(board, &base, &cover, digit)
+            .into_iter()
+            .filter(|i| fins.iter().all(|f| board.cells[*f as usize].sees(&board.cells[*i as usize])))
+            .collect();
+
+        // Only the smallest fish is kept for the same probabilities
+        if targets.is_empty() || !found.insert(targets.clone()) {
+            continue
+        }
+
+        let hashmap: HashMap<u8, HashSet<u8>> = targets
+            .into_iter()
+            .map(|i| (i, HashSet::from([digit])))
+            .collect();
+
+        let cells: Vec<u8> = fish_cells(board, &base, digit);
+
+        strategies.push(
+            Strategy::new(
+                kind,
+                hashmap
+            ).with_houses(base, cover).with_fins(fins)
+            .with_pattern(cells, vec![digit])
+        );
+    }
+
+    return strategies
+}
+
+
+/// The base and cover orientations of a franken fish, rows and blocks against columns
+/// and blocks or the other way around
+pub const FRANKEN_ORIENTATIONS: [(&[Orientation], &[Orientation]); 2] = [
+    (&[Orientation::Row, Orientation::Block], &[Orientation::Column, Orientation::Block]),
+    (&[Orientation::Column, Orientation::Block], &[Orientation::Row, Orientation::Block]),
+];
+
+
+/// Checks if the base houses are rows and blocks and the cover houses columns and
+/// blocks, or the other way around
+fn is_franken(base: &[House], cover: &[House]) -> bool {
+    let only = |houses: &[House], orientations: [Orientation; 2]| -> bool {
+        houses.iter().all(|h| orientations.contains(&house_orientation(h)))
+    };
+
+    return only(base, [Orientation::Row, Orientation::Block]) && only(cover, [Orientation::Column, Orientation::Block])
+        || only(base, [Orientation::Column, Orientation::Block]) && only(cover, [Orientation::Row, Orientation::Block])
+}
+
+
+/// FrankenFish is a fish in which blocks can be part of the base and cover houses
+///
+/// The base houses are rows and blocks, and the cover houses are columns and blocks,
+/// or the other way around. The base houses can't share a cell in which the digit
+/// is probable. As with a regular fish, the digit is removed from the cover houses
+/// outside the base houses. Fins are handled the same way as with a finned fish.
+///
+/// Every digit is searched on its own
+pub struct FrankenFish;
+
+impl SolveProbabilities for FrankenFish {
+    fn kind() -> StrategyKind {
+        return StrategyKind::FrankenFish
+    }
+
+    fn get_strategies(board: &Board) -> Vec<Strategy> {
+        let mut strategies: Vec<Strategy> = vec![];
+
+        for (base, cover) in FRANKEN_ORIENTATIONS {
+            for digit in 1..=9 {
+                strategies.extend(mixed_fish_logic(board, base, cover, digit, Self::kind(), |base, cover| {
+                    base.iter().chain(cover.iter()).any(|h| matches!(h, House::Block(_)))
+                }));
+            }
+        }
+
+        return strategies
+    }
+}
+
+
+/// MutantFish is a fish in which the base and cover houses can be any mix of rows,
+/// columns and blocks
+///
+/// Fish that are a regular or a franken fish are left out, those are found by the
+/// simpler strategies.
+///
+/// Every digit is searched on its own
+pub struct MutantFish;
+
+impl SolveProbabilities for MutantFish {
+    fn kind() -> StrategyKind {
+        return StrategyKind::MutantFish
+    }
+
+    fn get_strategies(board: &Board) -> Vec<Strategy> {
+        let all: [Orientation; 3] = [Orientation::Row, Orientation::Column, Orientation::Block];
+
+        return (1..=9)
+            .flat_map(|digit| mixed_fish_logic(board, &all, &all, digit, Self::kind(), |base, cover| !is_franken(base, cover)))
+            .collect()
+    }
+}
+
+
+/// Skyscraper uses two rows (or two columns) in which a digit is probable in two
+/// cells only
+///
+/// When one end of both conjugate pairs is in the same column, only one of them can
+/// hold the digit. So one of the other two ends holds the digit, and the digit can
+/// be removed from all cells that see both of these ends.
+///
+/// Every digit is searched on its own
+pub struct Skyscraper;
+
+impl SolveProbabilities for Skyscraper {
+    fn kind() -> StrategyKind {
+        return StrategyKind::Skyscraper
+    }
+
+    fn get_strategies(board: &Board) -> Vec<Strategy> {
+        return (1..=9).flat_map(|digit| turbot_logic(board, digit, Self::kind(), is_skyscraper)).collect()
+    }
+}
+
+
+/// TwoStringKite uses a row and a column in which a digit is probable in two cells
+/// only
+///
+/// When one end of the row and one end of the column are in the same block, only
+/// one of them can hold the digit. So one of the other two ends holds the digit,
+/// and the digit can be removed from all cells that see both of these ends.
+///
+/// Every digit is searched on its own
+pub struct TwoStringKite;
+
+impl SolveProbabilities for TwoStringKite {
+    fn kind() -> StrategyKind {
+        return StrategyKind::TwoStringKite
+    }
+
+    fn get_strategies(board: &Board) -> Vec<Strategy> {
+        return (1..=9).flat_map(|digit| turbot_logic(board, digit, Self::kind(), is_two_string_kite)).collect()
+    }
+}
+
+
+/// TurbotFish uses any two houses in which a digit is probable in two cells only
+///
+/// When one end of the first conjugate pair sees one end of the second pair, one of
+/// the other two ends holds the digit. The digit can be removed from all cells that
+/// see both of these ends. The Skyscraper and the Two-String Kite are special turbot
+/// fish, their patterns are skipped so this strategy only finds the rest.
+///
+/// Every digit is searched on its own
+pub struct TurbotFish;
+
+impl SolveProbabilities for TurbotFish {
+    fn kind() -> StrategyKind {
+        return StrategyKind::TurbotFish
+    }
+
+    fn get_strategies(board: &Board) -> Vec<Strategy> {
+        return (1..=9)
+            .flat_map(|digit| turbot_logic(board, digit, Self::kind(), |first, second, a, b| {
+                return !is_skyscraper(first, second, a, b) && !is_two_string_kite(first, second, a, b)
+            }))
+            .collect()
+    }
+}
+
+
+/// Checks if two conjugate pairs form a skyscraper, two parallel lines of which
+/// the ends that see each other are in the same column or row
+fn is_skyscraper(first: &House, second: &House, a: &Cell, b: &Cell) -> bool {
+    return match (first, second) {
+        (House::Row(_), House::Row(_)) => a.column() == b.column(),
+        (House::Column(_), House::Column(_)) => a.row() == b.row(),
+        _ => false,
+    }
+}
+
+
+/// Checks if two conjugate pairs form a two-string kite, a row and a column of
+/// which the ends that see each other are in the same block
+fn is_two_string_kite(first: &House, second: &House, a: &Cell, b: &Cell) -> bool {
+    return match (first, second) {
+        (House::Row(_), House::Column(_)) | (House::Column(_), House::Row(_)) => a.block() == b.block(),
+        _ => false,
+    }
+}
+
+
+/// Checks if a pattern of two conjugate pairs belongs to a strategy, gets the houses
+/// of both pairs and the two ends that see each other
+type TurbotPattern = fn(&House, &House, &Cell, &Cell) -> bool;
+
+/// Finds two conjugate pairs of a digit, of which one end of the first pair sees
+/// one end of the second pair. The digit is removed from the cells that see both
+/// other ends
+///
+/// ### Arguments
+///   board (&Board): the sudoku
+///   digit (u8): the digit
+///   kind (StrategyKind): the kind of the strategy
+///   is_pattern (TurbotPattern): if the pairs form the pattern of the strategy
+///
+/// ### Returns
+///   Vec<Strategy>: the strategies
+fn turbot_logic(board: &Board, digit: u8, kind: StrategyKind, is_pattern: TurbotPattern) -> Vec<Strategy> {
+    let mut strategies: Vec<Strategy> = vec![];
+    let mut found: HashSet<Vec<u8>> = HashSet::new();
+    let pairs: Vec<(House, u8, u8)> = conjugate_pairs(board, digit);
+
+    for (first, second) in pairs.iter().tuple_combinations() {
+        for ((a, a_end), (b, b_end)) in [(first.1, first.2), (first.2, first.1)]
+            .into_iter()
+            .cartesian_product([(second.1, second.2), (second.2, second.1)]) {
+            let cells: [&Cell; 4] = [a, a_end, b, b_end].map(|i| &board.cells[i as usize]);
+
+            if cells.iter().map(|c| c.index).unique().count() != 4
+                || !cells[0].sees(cells[2])
+                || !is_pattern(&first.0, &second.0, cells[0], cells[2]) {
+                continue
+            }
+
+            let hashmap: HashMap<u8, HashSet<u8>> = board.cells
+                .iter()
+                .filter(|c| !c.solved() && c.contains(&digit))
+                .filter(|c| c.sees(cells[1]) && c.sees(cells[3]))
+                .map(|c| (c.index, HashSet::from([digit])))
+                .collect();
+
+            if hashmap.is_empty() || !found.insert(hashmap.keys().copied().sorted().collect()) {
+                continue
+            }
+
+            strategies.push(
+                Strategy::new(
+                    kind,
+                    hashmap
+                ).with_houses(vec![first.0, second.0], vec![])
+                .with_pattern(vec![first.1, first.2, second.1, second.2], vec![digit])
+            );
+        }
+    }
+
+    return strategies
+}
+
+
+/// EmptyRectangle uses a block in which a digit is only probable in one row and one
+/// column of the block, and a conjugate pair outside of the block
+///
+/// When the first end of a conjugate pair in a column is in the row of the block, and
+/// the second end isn't, the digit can be removed from the cell in the row of the
+/// second end and the column of the block. When that cell holds the digit, the first
+/// end holds it too, which leaves no place in the block. The same goes for a pair in
+/// a row, with the rows and columns swapped.
+///
+/// Every digit is searched on its own
+pub struct EmptyRectangle;
+
+impl SolveProbabilities for EmptyRectangle {
+    fn kind() -> StrategyKind {
+        return StrategyKind::EmptyRectangle
+    }
+
+    fn get_strategies(board: &Board) -> Vec<Strategy> {
+        let mut strategies: Vec<Strategy> = vec![];
+        for digit in 1..=9 {
+            let pairs: Vec<(House, u8, u8)> = conjugate_pairs(board, digit);
+
+            for block in 0..9 {
+                let probable: Vec<Cell> = board.block(block)
+                    .missing()
+                    .into_iter()
+                    .filter(|c| c.contains(&digit))
+                    .collect();
+
+                if probable.len() < 2 {
+                    continue
+                }
+
+                let rows: Vec<u8> = probable.iter().map(|c| c.row()).unique().collect();
+                let columns: Vec<u8> = probable.iter().map(|c| c.column()).unique().collect();
+
+                for (row, column) in rows.iter().cartesian_product(columns.iter()) {
+                    if !probable.iter().all(|c| c.row() == *row || c.column() == *column) {
+                        continue
+                    }
+
+                    for (house, first, second) in &pairs {
+                        for (a, b) in [(*first, *second), (*second, *first)] {
+                            let (a, b): (&Cell, &Cell) = (&board.cells[a as usize], &board.cells[b as usize]);
+
+                            if a.block() == block || b.block() == block {
+                                continue
+                            }
+
+                            // The index of the cell that loses the digit
+                            let target: u8 = match house {
+                                House::Column(_) if a.row() == *row && b.row() != *row => b.row() * 9 + column,
+                                House::Row(_) if a.column() == *column && b.column() != *column => row * 9 + b.column(),
+                                _ => continue,
+                            };
+
+                            let cell: &Cell = &board.cells[target as usize];
+                            if cell.block() == block || cell.solved() || !cell.contains(&digit) {
+                                continue
+                            }
+
+                            let mut cells: Vec<u8> = probable.iter().map(|c| c.index).collect();
+                            cells.extend([a.index, b.index]);
+
+                            strategies.push(
+                                Strategy::new(
+                                    Self::kind(),
+                                    HashMap::from([(target, HashSet::from([digit]))])
+                                ).with_houses(vec![House::Block(block), *house], vec![])
+                                .with_pattern(cells, vec![digit])
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        return strategies
+    }
+}
+
+
+/// XYWing uses a bivalue pivot cell {x, y} that sees two bivalue cells {x, z}
+/// and {y, z}
+///
+/// Whatever the value of the pivot, one of the two wings will be z. So z can be
+/// removed from all cells that see both wings.
+///
+/// The index is used as the block in which the pivot is
+pub struct XYWing;
+
+impl SolveProbabilities for XYWing {
+    fn kind() -> StrategyKind {
+        return StrategyKind::XYWing
+    }
+
+    fn orientations() -> Vec<Orientation> {
+        return vec![Orientation::Block]
+    }
+
+    fn logic(board: &Board, _orientation: &Orientation, index: u8) -> Vec<Strategy> {
+        return wing_logic(
+            board,
+            index,
+            Self::kind(),
+            |pivot| pivot.probabilities.len() == 2,
+            |pivot, wing| {
+                wing.probabilities.len() == 2
+                    && wing.probabilities.iter().filter(|p| pivot.contains(p)).count() == 1
+            },
+            2
+        )
+    }
+}
+
+
+/// WWing uses two bivalue cells {x, y} that don't see each other, and a conjugate
+/// pair of x of which one end sees the first cell and the other end sees the second
+///
+/// One of the ends of the conjugate pair holds x, so one of the bivalue cells can't
+/// be x and has to be y. So y can be removed from all cells that see both bivalue cells.
+///
+/// The index is used as the block of the first bivalue cell
+pub struct WWing;
+
+impl SolveProbabilities for WWing {
+    fn kind() -> StrategyKind {
+        return StrategyKind::WWing
+    }
+
+    fn orientations() -> Vec<Orientation> {
+        return vec![Orientation::Block]
+    }
+
+    fn logic(board: &Board, orientation: &Orientation, index: u8) -> Vec<Strategy> {
+        let mut strategies: Vec<Strategy> = vec![];
+
+        for first in Self::create_subset(board, orientation, index).missing() {
+            if first.probabilities.len() != 2 {
+                continue
+            }
+
+            for second in board.cells.iter().filter(|c| c.index > first.index && c.probabilities == first.probabilities) {
+                if first.sees(second) {
+                    continue
+                }
+
+                for (x, y) in [(first.probabilities[0], first.probabilities[1]), (first.probabilities[1], first.probabilities[0])] {
+                    let linked: Option<(House, u8, u8)> = conjugate_pairs(board, x)
+                        .into_iter()
+                        .find(|(_, a, b)| {
+                            let (a, b): (&Cell, &Cell) = (&board.cells[*a as usize], &board.cells[*b as usize]);
+                            (a.sees(&first) && b.sees(second)) || (b.sees(&first) && a.sees(second))
+                        });
+
+                    let Some((house, a, b)) = linked else {
+                        continue
+                    };
+
+                    let hashmap: HashMap<u8, HashSet<u8>> = board.cells
+                        .iter()
+                        .filter(|c| !c.solved() && c.contains(&y))
+                        .filter(|c| c.sees(&first) && c.sees(second))
+                        .map(|c| (c.index, HashSet::from([y])))
+                        .collect();
+
+                    if hashmap.is_empty() {
+                        continue
+                    }
+
+                    strategies.push(
+                        Strategy::new(
+                            Self::kind(),
+                            hashmap
+                        ).with_houses(vec![house], vec![])
+                        .with_pattern(vec![first.index, second.index, a, b], vec![x, y])
+                    );
+                }
+            }
+        }
+
+        return strategies
+    }
+}
+
+
+/// XYZWing uses a pivot cell {x, y, z} that sees two bivalue cells {x, z}
+/// and {y, z}
+///
+/// One of the three cells will be z, so z can be removed from all cells that see
+/// the pivot and both wings.
+///
+/// The index is used as the block in which the pivot is
+pub struct XYZWing;
+
+impl SolveProbabilities for XYZWing {
+    fn kind() -> StrategyKind {
+        return StrategyKind::XYZWing
+    }
+
+    fn orientations() -> Vec<Orientation> {
+        return vec![Orientation::Block]
+    }
+
+    fn logic(board: &Board, _orientation: &Orientation, index: u8) -> Vec<Strategy> {
+        return wing_logic(
+            board,
+            index,
+            Self::kind(),
+            |pivot| pivot.probabilities.len() == 3,
+            |pivot, wing| {
+                wing.probabilities.len() == 2
+                    && wing.probabilities.iter().all(|p| pivot.contains(p))
+            },
+            2
+        )
+    }
+}
+
+
+/// WXYZWing uses a pivot cell that sees three wing cells, where the four cells
+/// together have four probabilities
+///
+/// When all cells that contain one of the probabilities see each other, except for
+/// the cells with z, then one of the cells with z will be z. Otherwise, the four
+/// cells would have to be filled with three digits that each can be placed once.
+/// So z can be removed from all cells that see every cell with z.
+///
+/// The index is used as the block in which the pivot is
+pub struct WXYZWing;
+
+impl SolveProbabilities for WXYZWing {
+    fn kind() -> StrategyKind {
+        return StrategyKind::WXYZWing
+    }
+
+    fn orientations() -> Vec<Orientation> {
+        return vec![Orientation::Block]
+    }
+
+    fn logic(board: &Board, _orientation: &Orientation, index: u8) -> Vec<Strategy> {
+        return wing_logic(
+            board,
+            index,
+            Self::kind(),
+            |pivot| pivot.probabilities.len() <= 4,
+            |_, wing| wing.probabilities.len() <= 4,
+            3
+        )
+    }
+}
+
+
+/// Searches for wings with the pivot in the given block. The pivot and the wings
+/// together should have one probability more than the number of wings. Of those
+/// probabilities, exactly one may be in cells that don't all see each other. That
+/// probability is removed from cells that see all cells that contain it.
+///
+/// ### Arguments
+///   board (&Board): the sudoku
+///   block (u8): the block in which the pivot is
+///   kind (StrategyKind): the kind of the strategy
+///   is_pivot (fn(&Cell) -> bool): if the cell can be the pivot
+///   is_wing (fn(&Cell, &Cell) -> bool): if the second cell can be a wing of the pivot
+///   wings (usize): the number of wings
+///
+/// ### Returns
+///   Vec<Strategy>: the strategies
+fn wing_logic(
+    board: &Board,
+    block: u8,
+    kind: StrategyKind,
+    is_pivot: fn(&Cell) -> bool,
+    is_wing: fn(&Cell, &Cell) -> bool,
+    wings: usize
+) -> Vec<Strategy> {
+    let mut strategies: Vec<Strategy> = vec![];
+
+    for pivot in board.block(block).missing().iter().filter(|c| is_pivot(c)) {
+        let candidates: Vec<&Cell> = board.cells
+            .iter()
+            .filter(|c| !c.solved() && pivot.sees(c) && is_wing(pivot, c))
+            .collect();
+
+        for combination in candidates.into_iter().combinations(wings) {
+            let mut cells: Vec<&Cell> = combination;
+            cells.push(pivot);
+
+            let digits: HashSet<u8> = cells
+                .iter()
+                .flat_map(|c| c.probabilities.iter().copied())
+                .collect();
+
+            if digits.len() != wings + 1 {
+                continue
+            }
+
+            // The digits of which the cells don't all see each other
+            let unrestricted: Vec<u8> = digits
+                .iter()
+                .copied()
+                .filter(|d| {
+                    cells
+                        .iter()
+                        .filter(|c| c.contains(d))
+                        .combinations(2)
+                        .any(|pair| !pair[0].sees(pair[1]))
+                })
+                .collect();
+
+            if unrestricted.len() != 1 {
+                continue
+            }
+
+            let z: u8 = unrestricted[0];
+            let z_cells: Vec<&&Cell> = cells.iter().filter(|c| c.contains(&z)).collect();
+
+            let hashmap: HashMap<u8, HashSet<u8>> = board.cells
+                .iter()
+                .filter(|c| !c.solved() && c.contains(&z))
+                .filter(|c| z_cells.iter().all(|z_cell| z_cell.sees(c)))
+                .map(|c| (c.index, HashSet::from([z])))
+                .collect();
+
+            if hashmap.is_empty() {
+                continue
+            }
+
+            strategies.push(
+                Strategy::new(
+                    kind,
+                    hashmap
+                ).with_pattern(cells.iter().map(|c| c.index).collect(), digits.into_iter().collect())
+            );
+        }
+    }
+
+    return strategies
+}
+
+
+/// Coloring follows the chains of conjugate pairs of a single digit
+///
+/// In a conjugate pair, exactly one of the two cells is the digit. All cells
+/// that are connected through conjugate pairs form a cluster, which is colored
+/// with two alternating colors. One of the colors is the digit, the other isn't.
+///
+/// - Color wrap: when two cells of the same color see each other, that color
+///   can't be the digit
+/// - Color trap: a cell that sees both colors can't be the digit
+/// - Multi coloring: when a color of one cluster sees a color of another cluster,
+///   at least one of the opposite colors is the digit. Cells that see both opposite
+///   colors can't be the digit. And when a color sees both colors of the other
+///   cluster, that color can't be the digit
+///
+/// Every digit is searched on its own
+pub struct Coloring;
+
+impl SolveProbabilities for Coloring {
+    fn kind() -> StrategyKind {
+        return StrategyKind::Coloring
+    }
+
+    fn get_strategies(board: &Board) -> Vec<Strategy> {
+        let mut strategies: Vec<Strategy> = vec![];
+        for digit in 1..=9 {
+
+            let clusters: Vec<[Vec<u8>; 2]> = color_clusters(&conjugate_pairs(board, digit));
+            let probable: Vec<&Cell> = board.cells
+                .iter()
+                .filter(|c| !c.solved() && c.contains(&digit))
+                .collect();
+
+            // Checks if any cell of the first color sees any cell of the second color
+            let sees = |first: &Vec<u8>, second: &Vec<u8>| -> bool {
+                first.iter().any(|i| second.iter().any(|j| board.cells[*i as usize].sees(&board.cells[*j as usize])))
+            };
+
+            // Removes the digit from all probable cells that see both colors
+            let trap = |first: &Vec<u8>, second: &Vec<u8>| -> HashMap<u8, HashSet<u8>> {
+                probable
+                    .iter()
+                    .filter(|c| !first.contains(&c.index) && !second.contains(&c.index))
+                    .filter(|c| sees(&vec![c.index], first) && sees(&vec![c.index], second))
+                    .map(|c| (c.index, HashSet::from([digit])))
+                    .collect()
+            };
+
+            // Removes the digit from all cells of the color
+            let wrap = |color: &Vec<u8>| -> HashMap<u8, HashSet<u8>> {
+                color.iter().map(|i| (*i, HashSet::from([digit]))).collect()
+            };
+
+            // The strategy for an elimination, with the colored cells it is based on
+            let colored = |hashmap: HashMap<u8, HashSet<u8>>, cells: &[u8]| -> Strategy {
+                Strategy::new(Self::kind(), hashmap).with_pattern(cells.to_vec(), vec![digit])
+            };
+
+            for cluster in &clusters {
+                let cells: Vec<u8> = cluster.concat();
+
+                for color in cluster {
+                    if sees(color, color) {
+                        strategies.push(colored(wrap(color), &cells));
+                    }
+                }
+
+                strategies.push(colored(trap(&cluster[0], &cluster[1]), &cells));
+            }
+
+            for (a, b) in clusters.iter().tuple_combinations() {
+                let cells: Vec<u8> = a.concat().into_iter().chain(b.concat()).collect();
+
+                for (color_a, color_b) in (0..2).cartesian_product(0..2) {
+                    if !sees(&a[color_a], &b[color_b]) {
+                        continue
+                    }
+
+                    strategies.push(colored(trap(&a[1 - color_a], &b[1 - color_b]), &cells));
+
+                    if sees(&a[color_a], &b[1 - color_b]) {
+                        strategies.push(colored(wrap(&a[color_a]), &cells));
+                    }
+                    if sees(&b[color_b], &a[1 - color_a]) {
+                        strategies.push(colored(wrap(&b[color_b]), &cells));
+                    }
+                }
+            }
+
+            strategies.retain(|s| !s.remove.is_empty());
+        }
+
+        return strategies
+    }
+}
+
+
+/// Gets the conjugate pairs of a digit. Two cells are a conjugate pair when they
+/// are the only cells of a row, column or block in which the digit is probable
+///
+/// ### Arguments
+///   board (&Board): the sudoku
+///   digit (u8): the digit
+///
+/// ### Returns
+///   Vec<(House, u8, u8)>: the house and the index of both cells
+fn conjugate_pairs(board: &Board, digit: u8) -> Vec<(House, u8, u8)> {
+    let mut pairs: Vec<(House, u8, u8)> = vec![];
+
+    for i in 0..9 {
+        for (house, subset) in [
+            (House::Row(i), board.row(i)),
+            (House::Column(i), board.column(i)),
+            (House::Block(i), board.block(i)),
+        ] {
+            if subset.contains(&digit) {
+                continue
+            }
+
+            let cells: Vec<u8> = subset.missing()
+                .iter()
+                .filter(|c| c.contains(&digit))
+                .map(|c| c.index)
+                .collect();
+
+            if cells.len() == 2 {
+                pairs.push((house, cells[0], cells[1]));
+            }
+        }
+    }
+
+    return pairs
+}
+
+
+/// Divides the cells of the conjugate pairs into clusters of connected cells.
+/// Each cluster is colored with two colors, so that the cells of every conjugate
+/// pair have a different color
+///
+/// ### Arguments
+///   pairs (&[(House, u8, u8)]): the conjugate pairs
+///
+/// ### Returns
+///   Vec<[Vec<u8>; 2]>: for every cluster the index of the cells of both colors
+fn color_clusters(pairs: &[(House, u8, u8)]) -> Vec<[Vec<u8>; 2]> {
+    let mut clusters: Vec<[Vec<u8>; 2]> = vec![];
+    let mut colored: HashSet<u8> = HashSet::new();
+
+    for (_, start, _) in pairs {
+        if colored.contains(start) {
+            continue
+        }
+
+        let mut cluster: [Vec<u8>; 2] = [vec![], vec![]];
+        let mut queue: Vec<(u8, usize)> = vec![(*start, 0)];
+        colored.insert(*start);
+
+        while let Some((cell, color)) = queue.pop() {
+            cluster[color].push(cell);
+
+            for (_, a, b) in pairs {
+                let other: u8 = if *a == cell { *b } else if *b == cell { *a } else { continue };
+                if colored.insert(other) {
+                    queue.push((other, 1 - color));
+                }
+            }
+        }
+
+        clusters.push(cluster);
+    }
+
+    return clusters
+}
+
+
+/// Medusa colors the probabilities of all digits at once (3D Medusa)
+///
+/// Two probabilities are linked when they are the only two probabilities of a cell,
+/// or the only two cells of a house in which a digit is probable. Exactly one of two
+/// linked probabilities is true, so every cluster of linked probabilities can be
+/// colored with two colors of which one is true. The rules that are applied are:
+///
+///    ColorTwiceInCell: a color appears twice in a cell, so the color is false
+///    ColorTwiceInHouse: a color appears twice for a digit in a house, so the color is false
+///    TwoColorsInCell: a cell has both colors, so the other probabilities of the cell are removed
+///    TwoColorsElsewhere: a probability sees the digit in both colors, so it is removed
+///    TwoColorsUnitAndCell: a probability is in a cell with one color and sees the digit
+///        in the other color, so it is removed
+///    CellEmptiedByColor: all probabilities of a cell see the digit in one color, so the
+///        color is false
+///
+/// The clusters span all digits, so they are built once for the whole board
+pub struct Medusa;
+
+impl SolveProbabilities for Medusa {
+    fn kind() -> StrategyKind {
+        return StrategyKind::Medusa
+    }
+
+    fn get_strategies(board: &Board) -> Vec<Strategy> {
+        let mut strategies: Vec<Strategy> = vec![];
+
+        for colors in medusa_clusters(board) {
+            // Checks if a probability sees the digit in a color, it can't be the
+            // same probability
+            let sees = |i: u8, d: u8, color: bool| -> bool {
+                colors.iter().any(|((j, e), c)| {
+                    *c == color && *e == d && board.cells[i as usize].sees(&board.cells[*j as usize])
+                })
+            };
+
+            let uncolored: Vec<(u8, u8)> = board.cells
+                .iter()
+                .filter(|c| !c.solved())
+                .flat_map(|c| c.probabilities.iter().map(|d| (c.index, *d)))
+                .filter(|p| !colors.contains_key(p))
+                .collect();
+
+            let mut rules: Vec<(MedusaRule, HashMap<u8, HashSet<u8>>)> = vec![];
+
+            // The colors that are false, with the rule that proves it
+            for color in [false, true] {
+                let of_color: Vec<(u8, u8)> = colors
+                    .iter()
+                    .filter(|(_, c)| **c == color)
+                    .map(|(p, _)| *p)
+                    .collect();
+
+                let rule: Option<MedusaRule> = if of_color.iter().map(|(i, _)| i).duplicates().next().is_some() {
+                    Some(MedusaRule::ColorTwiceInCell)
+                } else if of_color.iter().any(|(i, d)| sees(*i, *d, color)) {
+                    Some(MedusaRule::ColorTwiceInHouse)
+                } else if board.cells.iter().any(|c| {
+                    !c.solved() && c.probabilities.iter().all(|d| !colors.contains_key(&(c.index, *d)) && sees(c.index, *d, color))
+                }) {
+                    Some(MedusaRule::CellEmptiedByColor)
+                } else {
+                    None
+                };
+
+                if let Some(rule) = rule {
+                    let mut hashmap: HashMap<u8, HashSet<u8>> = HashMap::new();
+                    for (i, d) in of_color {
+                        hashmap.entry(i).or_default().insert(d);
+                    }
+                    rules.push((rule, hashmap));
+                }
+            }
+
+            let mut two_colors_in_cell: HashMap<u8, HashSet<u8>> = HashMap::new();
+            let mut two_colors_elsewhere: HashMap<u8, HashSet<u8>> = HashMap::new();
+            let mut unit_and_cell: HashMap<u8, HashSet<u8>> = HashMap::new();
+
+            for (i, d) in uncolored {
+                let cell_colors: HashSet<bool> = board.cells[i as usize].probabilities
+                    .iter()
+                    .filter_map(|e| colors.get(&(i, *e)).copied())
+                    .collect();
+
+                if cell_colors.len() == 2 {
+                    two_colors_in_cell.entry(i).or_default().insert(d);
+                } else if sees(i, d, false) && sees(i, d, true) {
+                    two_colors_elsewhere.entry(i).or_default().insert(d);
+                } else if cell_colors.len() == 1 && sees(i, d, !cell_colors.contains(&true)) {
+                    unit_and_cell.entry(i).or_default().insert(d);
+                }
+            }
+
+            rules.push((MedusaRule::TwoColorsInCell, two_colors_in_cell));
+            rules.push((MedusaRule::TwoColorsElsewhere, two_colors_elsewhere));
+            rules.push((MedusaRule::TwoColorsUnitAndCell, unit_and_cell));
+
+            for (rule, hashmap) in rules {
+                if hashmap.is_empty() {
+                    continue
+                }
+
+                strategies.push(
+                    Strategy::new(
+                        Self::kind(),
+                        hashmap
+                    ).with_rule(rule)
+                    .with_pattern(
+                        colors.keys().map(|(i, _)| *i).collect(),
+                        colors.keys().map(|(_, d)| *d).collect()
+                    )
+                );
+            }
+        }
+
+        return strategies
+    }
+}
+
+
+/// Divides the probabilities of the board into clusters, in which the probabilities
+/// are linked by bivalue cells and conjugate pairs. Every cluster is colored with
+/// two colors, so that linked probabilities have a different color. Clusters that
+/// can't be colored like that are left out
+///
+/// ### Arguments
+///   board (&Board): the sudoku
+///
+/// ### Returns
+///   Vec<HashMap<(u8, u8), bool>>: for every cluster the index of the cell and the
+///       digit of each probability, with its color
+fn medusa_clusters(board: &Board) -> Vec<HashMap<(u8, u8), bool>> {
+    let mut links: HashMap<(u8, u8), Vec<(u8, u8)>> = HashMap::new();
+
+    let mut link = |a: (u8, u8), b: (u8, u8)| {
+        links.entry(a).or_default().push(b);
+        links.entry(b).or_default().push(a);
+    };
+
+    for c in board.cells.iter().filter(|c| c.probabilities.len() == 2) {
+        link((c.index, c.probabilities[0]), (c.index, c.probabilities[1]));
+    }
+
+    for digit in 1..=9 {
+        for (_, a, b) in conjugate_pairs(board, digit) {
+            link((a, digit), (b, digit));
+        }
+    }
+
+    let mut clusters: Vec<HashMap<(u8, u8), bool>> = vec![];
+    let mut seen: HashSet<(u8, u8)> = HashSet::new();
+
+    for start in links.keys().sorted() {
+        if seen.contains(start) {
+            continue
+        }
+
+        let mut colors: HashMap<(u8, u8), bool> = HashMap::from([(*start, false)]);
+        let mut stack: Vec<(u8, u8)> = vec![*start];
+        let mut consistent: bool = true;
+
+        while let Some(p) = stack.pop() {
+            for q in &links[&p] {
+                match colors.get(q) {
+                    Some(color) => consistent &= *color != colors[&p],
+                    None => {
+                        colors.insert(*q, !colors[&p]);
+                        stack.push(*q);
+                    }
+                }
+            }
+        }
+
+        seen.extend(colors.keys());
+
+        if consistent {
+            clusters.push(colors);
+        }
+    }
+
+    return clusters
+}
+
+
+/// UniqueRectangle prevents a deadly pattern: four cells in two rows, two columns
+/// and two blocks that can only be {a, b}. Such a pattern could be swapped, so the
+/// puzzle would have two solutions. When the puzzle has a single solution, at least
+/// one of the cells with extra probabilities (the roof) is one of its extras.
+///
+/// - Type 1: only one cell has extras, so a and b can be removed from it
+/// - Type 2 and 5: all roof cells have the same single extra, that extra can be
+///   removed from all cells that see every roof cell
+/// - Type 3: the extras of two roof cells act as a single cell, which can form a
+///   naked subset with other cells of a house they share
+/// - Type 4: a or b is only probable in the two roof cells of a house they share, so
+///   the other one can be removed from the roof cells
+/// - Type 6: with a diagonal roof, a or b is only probable in the rectangle in both
+///   rows and both columns. It would form a deadly pattern when placed in the roof,
+///   so it is removed from the roof cells
+///
+/// The index is used as the block of the top left cell of the rectangle
+pub struct UniqueRectangle;
+
+impl SolveProbabilities for UniqueRectangle {
+    fn kind() -> StrategyKind {
+        return StrategyKind::UniqueRectangle
+    }
+
+    fn orientations() -> Vec<Orientation> {
+        return vec![Orientation::Block]
+    }
+
+    fn requires_uniqueness() -> bool {
+        return true
+    }
+
+    fn logic(board: &Board, _orientation: &Orientation, index: u8) -> Vec<Strategy> {
+        let mut eliminations: Vec<Strategy> = vec![];
+
+        for (corners, a, b) in unique_rectangles(board, index) {
+            // The strategy for an elimination, with the corners and the digits of the rectangle
+            let pattern = |hashmap: HashMap<u8, HashSet<u8>>| {
+                Strategy::new(Self::kind(), hashmap).with_pattern(corners.to_vec(), vec![a, b])
+            };
+            let cells: Vec<&Cell> = corners.iter().map(|i| &board.cells[*i as usize]).collect();
+            let roof: Vec<&Cell> = cells.iter().copied().filter(|c| c.probabilities.len() > 2).collect();
+            let extras: HashSet<u8> = roof
+                .iter()
+                .flat_map(|c| c.probabilities.iter().copied())
+                .filter(|p| *p != a && *p != b)
+                .collect();
+
+            // Type 1
+            if roof.len() == 1 {
+                eliminations.push(pattern(HashMap::from([(roof[0].index, HashSet::from([a, b]))])));
+                continue
+            }
+
+            // Type 2 and 5
+            if extras.len() == 1 && roof.iter().all(|c| c.probabilities.len() == 3) {
+                let extra: u8 = *extras.iter().next().unwrap();
+                eliminations.push(pattern(
+                    board.cells
+                        .iter()
+                        .filter(|c| !c.solved() && c.contains(&extra))
+                        .filter(|c| roof.iter().all(|r| r.sees(c)))
+                        .map(|c| (c.index, HashSet::from([extra])))
+                        .collect()
+                ));
+            }
+
+            if roof.len() != 2 {
+                continue
+            }
+
+            // Type 3 and 4 use the houses that are shared by the roof
+            for house in shared_houses(roof[0], roof[1]) {
+                let others: Vec<Cell> = house_subset(board, &house)
+                    .missing()
+                    .into_iter()
+                    .filter(|c| c.index != roof[0].index && c.index != roof[1].index)
+                    .collect();
+
+                for k in 1..=3 {
+                    for combination in others.iter().combinations(k) {
+                        let mut digits: HashSet<u8> = extras.clone();
+                        for c in &combination {
+                            digits.extend(c.as_set());
+                        }
+
+                        if digits.len() != k + 1 {
+                            continue
+                        }
+
+                        eliminations.push(pattern(
+                            others
+                                .iter()
+                                .filter(|c| combination.iter().all(|other| other.index != c.index))
+                                .map(|c| (c.index, digits.clone()))
+                                .collect()
+                        ));
+                    }
+                }
+
+                // The locked digit can't be placed anywhere else in the house, solved cells included
+                for (locked, other) in [(a, b), (b, a)] {
+                    let elsewhere: bool = house_subset(board, &house).cells
+                        .iter()
+                        .any(|c| c.index != roof[0].index && c.index != roof[1].index && c.contains(&locked));
+
+                    if !elsewhere {
+                        eliminations.push(pattern(HashMap::from([
+                            (roof[0].index, HashSet::from([other])),
+                            (roof[1].index, HashSet::from([other])),
+                        ])));
+                    }
+                }
+            }
+
+            // Type 6
+            if !roof[0].sees(roof[1]) {
+                for digit in [a, b] {
+                    let locked: bool = cells
+                        .iter()
+                        .flat_map(|c| [board.row(c.row()), board.column(c.column())])
+                        .all(|subset| {
+                            subset.missing()
+                                .iter()
+                                .filter(|c| c.contains(&digit))
+                                .all(|c| corners.contains(&c.index))
+                        });
+
+                    if locked {
+                        eliminations.push(pattern(
+                            roof.iter().map(|c| (c.index, HashSet::from([digit]))).collect()
+                        ));
+                    }
+                }
+            }
+        }
+
+        return eliminations
+            .into_iter()
+            .filter(|s| !s.remove.is_empty())
+            .collect()
+    }
+}
+
+
+/// HiddenUniqueRectangle uses a rectangle of which one corner only has the
+/// probabilities {a, b}. When a is only probable in the rectangle, in the row and
+/// in the column of the opposite corner, that corner can't be b. Otherwise the
+/// opposite corner would be b and the other corners a, which makes a deadly
+/// pattern.
+///
+/// The index is used as the block of the top left cell of the rectangle
+pub struct HiddenUniqueRectangle;
+
+impl SolveProbabilities for HiddenUniqueRectangle {
+    fn kind() -> StrategyKind {
+        return StrategyKind::HiddenUniqueRectangle
+    }
+
+    fn orientations() -> Vec<Orientation> {
+        return vec![Orientation::Block]
+    }
+
+    fn requires_uniqueness() -> bool {
+        return true
+    }
+
+    fn logic(board: &Board, _orientation: &Orientation, index: u8) -> Vec<Strategy> {
+        let mut strategies: Vec<Strategy> = vec![];
+
+        for (corners, a, b) in unique_rectangles(board, index) {
+            for (floor, opposite) in [(0, 3), (1, 2), (2, 1), (3, 0)] {
+                if board.cells[corners[floor] as usize].probabilities.len() != 2 {
+                    continue
+                }
+
+                let cell: &Cell = &board.cells[corners[opposite] as usize];
+
+                for (locked, other) in [(a, b), (b, a)] {
+                    let strong: bool = [board.row(cell.row()), board.column(cell.column())]
+                        .iter()
+                        .all(|subset| {
+                            subset.missing()
+                                .iter()
+                                .filter(|c| c.contains(&locked))
+                                .all(|c| corners.contains(&c.index))
+                        });
+
+                    if strong {
+                        strategies.push(
+                            Strategy::new(
+                                Self::kind(),
+                                HashMap::from([(cell.index, HashSet::from([other]))])
+                            ).with_pattern(corners.to_vec(), vec![a, b])
+                        );
+                    }
+                }
+            }
+        }
+
+        return strategies
+    }
+}
+
+
+/// Gets the rectangles of which the top left corner is in the given block. The
+/// corners should be in two rows, two columns and two blocks, and all four cells
+/// should share two probabilities
+///
+/// ### Arguments
+///   board (&Board): the sudoku
+///   block (u8): the block of the top left corner
+///
+/// ### Returns
+///   Vec<([u8; 4], u8, u8)>: the index of the top left, top right, bottom left and
+///   bottom right corner, and the two shared probabilities
+fn unique_rectangles(board: &Board, block: u8) -> Vec<([u8; 4], u8, u8)> {
+    let mut rectangles: Vec<([u8; 4], u8, u8)> = vec![];
+
+    for top_left in board.block(block).missing() {
+        let (row, column) = (top_left.row(), top_left.column());
+
+        for (other_row, other_column) in ((row + 1)..9).cartesian_product((column + 1)..9) {
+            // Exactly one of the sides should stay within the blocks
+            if (row / 3 == other_row / 3) == (column / 3 == other_column / 3) {
+                continue
+            }
+
+            let corners: [u8; 4] = [
+                row * 9 + column,
+                row * 9 + other_column,
+                other_row * 9 + column,
+                other_row * 9 + other_column,
+            ];
+
+            if corners.iter().any(|i| board.cells[*i as usize].solved()) {
+                continue
+            }
+
+            let shared: Vec<u8> = (1..=9)
+                .filter(|p| corners.iter().all(|i| board.cells[*i as usize].contains(p)))
+                .collect();
+
+            for pair in shared.into_iter().combinations(2) {
+                rectangles.push((corners, pair[0], pair[1]));
+            }
+        }
+    }
+
+    return rectangles
+}
+
+
+/// Gets the houses that two cells share
+fn shared_houses(first: &Cell, second: &Cell) -> Vec<House> {
+    let mut houses: Vec<House> = vec![];
+    if first.row() == second.row() {
+        houses.push(House::Row(first.row()));
+    }
+    if first.column() == second.column() {
+        houses.push(House::Column(first.column()));
+    }
+    if first.block() == second.block() {
+        houses.push(House::Block(first.block()));
+    }
+    return houses
+}
+
+
+/// Gets the subset of a house
+fn house_subset(board: &Board, house: &House) -> Subset {
+    return match house {
+        House::Row(i) => board.row(*i),
+        House::Column(i) => board.column(*i),
+        House::Block(i) => board.block(*i),
+    }
+}
+
+
+/// BivalueUniversalGrave (BUG+1) is found when all unsolved cells have two
+/// probabilities, except for a single cell with three probabilities
+///
+/// Without the extra probability, every probability would appear twice in each
+/// house, which has at least two solutions. So the cell has to be the probability
+/// that appears three times in its row, column and block. The other probabilities
+/// are removed from the cell.
+///
+/// The index is used as the block of the cell with three probabilities
+pub struct BivalueUniversalGrave;
+
+impl SolveProbabilities for BivalueUniversalGrave {
+    fn kind() -> StrategyKind {
+        return StrategyKind::BivalueUniversalGrave
+    }
+
+    fn orientations() -> Vec<Orientation> {
+        return vec![Orientation::Block]
+    }
+
+    fn requires_uniqueness() -> bool {
+        return true
+    }
+
+    fn logic(board: &Board, _orientation: &Orientation, index: u8) -> Vec<Strategy> {
+        let mut strategies: Vec<Strategy> = vec![];
+
+        let unsolved: Vec<&Cell> = board.cells.iter().filter(|c| !c.solved()).collect();
+        let trivalue: Vec<&&Cell> = unsolved.iter().filter(|c| c.probabilities.len() != 2).collect();
+
+        if trivalue.len() != 1 || trivalue[0].probabilities.len() != 3 || trivalue[0].block() != index {
+            return strategies
+        }
+
+        let cell: &Cell = trivalue[0];
+        let houses: [Subset; 3] = [board.row(cell.row()), board.column(cell.column()), board.block(cell.block())];
+
+        // The probability that would break the grave appears three times in each house
+        let extra: Vec<u8> = cell.probabilities
+            .iter()
+            .copied()
+            .filter(|p| {
+                houses
+                    .iter()
+                    .all(|subset| subset.missing().iter().filter(|c| c.contains(p)).count() == 3)
+            })
+            .collect();
+
+        if extra.len() != 1 {
+            return strategies
+        }
+
+        // Every other probability must appear exactly twice in each of the 27 houses
+        let graves: bool = (0..9).all(|i| {
+            [board.row(i), board.column(i), board.block(i)].iter().all(|subset| {
+                let missing: Vec<Cell> = subset.missing();
+                (1..10).all(|p| {
+                    let count: usize = missing.iter().filter(|c| c.contains(&p)).count();
+                    let expected: usize = if p == extra[0] && missing.iter().any(|c| c.index == cell.index) { 3 } else { 2 };
+                    count == 0 || count == expected
+                })
+            })
+        });
+
+        if !graves {
+            return strategies
+        }
+
+        strategies.push(
+            Strategy::new(
+                Self::kind(),
+                HashMap::from([(
+                    cell.index,
+                    cell.probabilities.iter().copied().filter(|p| *p != extra[0]).collect()
+                )])
+            ).with_houses(vec![House::Row(cell.row()), House::Column(cell.column()), House::Block(cell.block())], vec![])
+            .with_pattern(vec![cell.index], extra)
+        );
+
+        return strategies
+    }
+}
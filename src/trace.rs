@@ -0,0 +1,162 @@
+use std::collections::{HashMap, HashSet};
+use crate::board::*;
+
+use itertools::Itertools;
+
+
+/// ### Step
+///
+/// A single strategy that was applied to the board while solving. Only the
+/// probabilities that were actually removed are stored, so a step always
+/// describes a change of the board. A placement is stored as the removal of the
+/// other probabilities of the cell, and as the placed value.
+///
+/// ### Attributes
+///    kind (StrategyKind): The technique of the strategy
+///    removed (HashMap<u8, HashSet<u8>>): The index of the cell and the probabilities that were removed
+///    placed (HashMap<u8, u8>): The index of the cell and the value, for cells solved by this step
+///    before (Board): The board before the strategy was applied
+///    after (Board): The board after the strategy was applied
+pub struct Step {
+    pub kind: StrategyKind,
+    pub removed: HashMap<u8, HashSet<u8>>,
+    pub placed: HashMap<u8, u8>,
+    pub before: Board,
+    pub after: Board,
+}
+
+impl Step {
+    /// Applies the strategy to the board and records the change
+    ///
+    /// ### Arguments
+    ///    board (&mut Board): the sudoku, will be adjusted by the strategy
+    ///    strategy (Strategy): the strategy that should be applied
+    ///
+    /// ### Returns
+    ///    Option<Step>: the step, None when the strategy didn't change the board
+    pub fn record(board: &mut Board, strategy: Strategy) -> Option<Step> {
+        let mut removed: HashMap<u8, HashSet<u8>> = strategy.remove
+            .iter()
+            .map(|(i, p)| (*i, p.intersection(&board.cells[*i as usize].as_set()).copied().collect()))
+            .filter(|(_, p): &(u8, HashSet<u8>)| !p.is_empty())
+            .collect();
+
+        // A placement removes the other probabilities of the cell
+        for (i, value) in &strategy.place {
+            let others: HashSet<u8> = board.cells[*i as usize].as_set()
+                .into_iter()
+                .filter(|p| p != value)
+                .collect();
+
+            if !others.is_empty() {
+                removed.entry(*i).or_default().extend(others);
+            }
+        }
+
+        if removed.is_empty() {
+            return None
+        }
+
+        let before: Board = board.clone();
+        let kind: StrategyKind = strategy.kind;
+        board.apply_strategy(strategy);
+
+        let placed: HashMap<u8, u8> = removed
+            .keys()
+            .filter(|i| board.cells[**i as usize].solved())
+            .map(|i| (*i, board.get(*i)))
+            .collect();
+
+        return Some(Step {
+            kind,
+            removed,
+            placed,
+            before,
+            after: board.clone(),
+        })
+    }
+
+    /// Applies the recorded change to a board
+    ///
+    /// ### Arguments
+    ///    board (&mut Board): the sudoku
+    pub fn replay(&self, board: &mut Board) {
+        board.apply_strategy(
+            Strategy::new(self.kind, self.removed.clone()).with_place(self.placed.clone())
+        )
+    }
+
+    /// Describes the step in the r/c notation, like "Hidden Single r3c5 = 7" or
+    /// "Pointing r1c2 <> 3". The removals from cells that are placed are left out,
+    /// the cells are sorted by index
+    ///
+    /// ### Returns
+    ///    String: the description
+    pub fn description(&self) -> String {
+        let mut changes: Vec<String> = self.placed
+            .keys()
+            .sorted()
+            .map(|i| format!("{} = {}", self.before.cells[*i as usize].name(), self.placed[i]))
+            .collect();
+
+        changes.extend(
+            self.removed
+                .keys()
+                .filter(|i| !self.placed.contains_key(i))
+                .sorted()
+                .map(|i| format!(
+                    "{} <> {}",
+                    self.before.cells[*i as usize].name(),
+                    self.removed[i].iter().sorted().join("/")
+                ))
+        );
+
+        return format!("{} {}", self.kind.display_name(), changes.join(", "))
+    }
+
+    /// Prints the description of the step
+    pub fn print(&self) {
+        println!("{}", self.description());
+    }
+}
+
+
+/// ### SolveLog
+///
+/// Contains all steps of a solve in the order they were applied
+#[derive(Default)]
+pub struct SolveLog {
+    pub steps: Vec<Step>,
+}
+
+impl SolveLog {
+    /// Creates an empty log
+    pub fn new() -> SolveLog {
+        return SolveLog {
+            steps: vec![],
+        }
+    }
+
+    /// Replays the log onto a fresh board
+    ///
+    /// ### Arguments
+    ///    puzzle (&str): the puzzle string the solve started from
+    ///
+    /// ### Returns
+    ///    Board: the board after all steps were applied
+    pub fn replay(&self, puzzle: &str) -> Board {
+        let mut board: Board = Board::from_string(puzzle);
+        for step in &self.steps {
+            step.replay(&mut board);
+        }
+        return board
+    }
+
+    /// Prints every step with its number
+    pub fn print(&self) {
+        for (n, step) in self.steps.iter().enumerate() {
+            print!("Step {}: ", n + 1);
+            step.print();
+        }
+    }
+}
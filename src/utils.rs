@@ -1,15 +1,14 @@
 use std::fs;
-use crate::lib::Board;
+use crate::board::Board;
 
 
 /// Solves a sudoku with brute-force
 ///
 /// ### Args:
-///     board (Board): The board that needs to be solved
+///   board (Board): The board that needs to be solved
 ///
 /// ### Returns:
-///     The solved board
-#[allow(dead_code)]
+///   The solved board
 pub fn brute_force(mut board: Board) -> Board {
     let blanks: Vec<u8> = board.blanks();
     if blanks.is_empty() {
@@ -65,16 +64,14 @@ pub fn brute_force(mut board: Board) -> Board {
 /// and solution of that puzzle
 ///
 /// ### Returns:
-///     A Vector containing a Vector that represents a sudoku,
-///     the first element is the puzzle and the second the solution
+///   A Vector containing a Vector that represents a sudoku,
+///   the first element is the puzzle and the second the solution
 ///
-#[allow(dead_code)]
 pub fn import_puzzles_from_file() -> Vec<Vec<String>> {
     return fs::read_to_string(r"puzzles.txt")
         .expect("It should read the file")
         .lines()
         .map(|x| x
-            .to_string()
             .split(", ")
             .map(|x| x.to_string())
             .collect())